@@ -0,0 +1,153 @@
+use std::time::{Duration, Instant};
+
+use crate::{AllocStats, Error, Part, Solution, measure, solve};
+
+/// Every sample of one part, sorted from fastest to slowest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "timings need at least one sample");
+        samples.sort();
+        Timings { samples }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        self.samples[self.samples.len() / 2]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+}
+
+/// Allocations are only counted when [`CountingAllocator`](crate::CountingAllocator)
+/// is the global allocator, from the last run of each step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    pub parse: Duration,
+    pub parse_memory: Option<AllocStats>,
+    pub parts: Vec<(Part, Timings, Option<AllocStats>)>,
+}
+
+impl BenchResult {
+    pub fn print(&self) {
+        println!("Parse: {:.1?}{}", self.parse, memory(self.parse_memory));
+        for (part, timings, stats) in &self.parts {
+            println!(
+                "Part {part}: min {:.1?}, median {:.1?}, max {:.1?}{}",
+                timings.min(),
+                timings.median(),
+                timings.max(),
+                memory(*stats)
+            );
+        }
+    }
+}
+
+fn memory(stats: Option<AllocStats>) -> String {
+    stats.map(|stats| format!(", {stats}")).unwrap_or_default()
+}
+
+/// Parses once, then solves each part `iterations` times on the same input.
+pub(crate) fn bench<S: Solution>(
+    solution: &S,
+    input: &str,
+    parts: &[Part],
+    config: &S::Config,
+    iterations: usize,
+) -> Result<BenchResult, Error> {
+    let ((input, parse), parse_memory) = measure(|| {
+        let start = Instant::now();
+        (solution.parse(input, config), start.elapsed())
+    });
+    let input = input?;
+
+    let parts = parts
+        .iter()
+        .map(|part| {
+            // Fail once up front, rather than timing an error.
+            solve(solution, &input, *part)?;
+            let mut stats = None;
+            let samples = (0..iterations)
+                .map(|_| {
+                    let elapsed;
+                    (elapsed, stats) = measure(|| {
+                        let start = Instant::now();
+                        let _ = solve(solution, &input, *part);
+                        start.elapsed()
+                    });
+                    elapsed
+                })
+                .collect();
+            Ok((*part, Timings::new(samples), stats))
+        })
+        .collect::<Result<_, Error>>()?;
+
+    Ok(BenchResult {
+        parse,
+        parse_memory,
+        parts,
+    })
+}
+
+/// Criterion benchmarks for parsing and both parts of a day, for the
+/// `benches/` target of every day crate, with the default config. Days without
+/// an input in the inputs directory are skipped.
+#[cfg(feature = "criterion")]
+pub fn criterion<S: Solution>(c: &mut criterion::Criterion, solution: &S) {
+    use std::{env, hint::black_box, path::PathBuf};
+
+    use crate::{INPUT_DIR_VAR, InputSource};
+
+    // Benchmarks run from the day's crate, so default to the workspace root.
+    let dir = env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| concat!(env!("CARGO_MANIFEST_DIR"), "/..").into());
+    let input = match InputSource::Directory(dir).read(S::DAY) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Skipping day {}: {e}", S::DAY);
+            return;
+        }
+    };
+    let config = S::Config::default();
+    let parsed = match solution.parse(&input, &config) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Skipping day {}: {e}", S::DAY);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day-{:02}", S::DAY));
+    group.bench_function("parse", |b| {
+        b.iter(|| solution.parse(black_box(&input), &config))
+    });
+    for part in Part::ALL {
+        group.bench_function(format!("part-{part}"), |b| {
+            b.iter(|| solve(solution, black_box(&parsed), part))
+        });
+    }
+    group.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_samples() {
+        let timings = Timings::new([5, 1, 3, 2, 4].map(Duration::from_millis).to_vec());
+        assert_eq!(timings.min(), Duration::from_millis(1));
+        assert_eq!(timings.median(), Duration::from_millis(3));
+        assert_eq!(timings.max(), Duration::from_millis(5));
+    }
+}
@@ -0,0 +1,89 @@
+use std::{fmt, str::FromStr};
+
+use crate::Error;
+
+/// Key/value pairs that change a day's [`Config`], like `size = 7` from an
+/// examples manifest or `--set size=7` on the command line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Params<'a>(pub &'a [(&'a str, &'a str)]);
+
+/// Numbers that a day hard-codes for the real puzzle but that its examples use
+/// different values for, like the size of a grid or the number of steps. The
+/// default is the real puzzle.
+pub trait Config: Default + Clone + fmt::Debug {
+    /// Changes the value called `key`, or fails if there is no such value.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error>;
+
+    /// The default config with every parameter applied.
+    fn from_params(params: Params) -> Result<Self, Error> {
+        let mut config = Self::default();
+        for (key, value) in params.0 {
+            config.set(key, value)?;
+        }
+        Ok(config)
+    }
+}
+
+/// For the days that always use the same numbers.
+impl Config for () {
+    fn set(&mut self, key: &str, _: &str) -> Result<(), Error> {
+        Err(unknown_param(key))
+    }
+}
+
+/// Parses the value of a parameter, for implementations of [`Config::set`].
+pub fn parse_param<T: FromStr>(key: &str, value: &str) -> Result<T, Error> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for parameter `{key}`").into())
+}
+
+pub fn unknown_param(key: &str) -> Error {
+    format!("unknown parameter `{key}`").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Grid {
+        size: usize,
+        bytes: usize,
+    }
+
+    impl Default for Grid {
+        fn default() -> Self {
+            Grid {
+                size: 71,
+                bytes: 1024,
+            }
+        }
+    }
+
+    impl Config for Grid {
+        fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+            match key {
+                "size" => self.size = parse_param(key, value)?,
+                "bytes" => self.bytes = parse_param(key, value)?,
+                _ => return Err(unknown_param(key)),
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn reads_params() {
+        assert_eq!(
+            Grid::from_params(Params(&[("size", "7")])).unwrap(),
+            Grid {
+                size: 7,
+                bytes: 1024
+            }
+        );
+        assert!(Grid::from_params(Params(&[("bytes", "x")])).is_err());
+        assert!(Grid::from_params(Params(&[("steps", "100")])).is_err());
+        assert!(<()>::from_params(Params(&[("size", "7")])).is_err());
+        assert!(<()>::from_params(Params::default()).is_ok());
+    }
+}
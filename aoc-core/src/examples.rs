@@ -0,0 +1,143 @@
+/// Includes the `#[test]` cases that [`generate`] wrote from the crate's
+/// `examples.toml`.
+#[macro_export]
+macro_rules! examples {
+    () => {
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
+
+/// Turns `examples.toml` into one `#[test]` per example, for the build script
+/// of a day crate:
+///
+/// ```toml
+/// [[example]]
+/// file = "input-test.txt"   # or `input = "..."` for short inputs
+/// part = 1
+/// params = { blinks = 6 }   # optional, sets the day's `Config`
+/// answer = 22
+/// name = "six_blinks"       # optional, defaults to `part_1_input_test`
+/// ```
+#[cfg(feature = "examples")]
+pub fn generate() {
+    use std::{env, fs, path::Path};
+
+    let dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let day = env::var("CARGO_PKG_NAME")
+        .expect("CARGO_PKG_NAME is set by cargo")
+        .replace("day-", "Day");
+    let manifest = Path::new(&dir).join("examples.toml");
+    println!("cargo::rerun-if-changed={}", manifest.display());
+
+    let tests = match fs::read_to_string(&manifest) {
+        Ok(text) => {
+            render(&text, &dir, &day).unwrap_or_else(|e| panic!("{}: {e}", manifest.display()))
+        }
+        Err(_) => String::new(),
+    };
+    let out = Path::new(&env::var("OUT_DIR").expect("OUT_DIR is set by cargo")).join("examples.rs");
+    fs::write(out, tests).expect("could not write the generated examples");
+}
+
+#[cfg(feature = "examples")]
+fn render(manifest: &str, dir: &str, day: &str) -> Result<String, String> {
+    use std::{collections::HashSet, path::Path};
+
+    use toml::{Table, Value};
+
+    let table: Table = manifest
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+    let examples = match table.get("example") {
+        Some(Value::Array(examples)) => examples.as_slice(),
+        Some(_) => return Err("`example` should be an array of tables".to_string()),
+        None => &[],
+    };
+
+    let mut names = HashSet::new();
+    let mut tests = String::new();
+    for (index, example) in examples.iter().enumerate() {
+        let context = |message: &str| format!("example {}: {message}", index + 1);
+        let field = |key: &str| example.get(key);
+        let scalar = |value: &Value| match value {
+            Value::String(text) => Ok(text.clone()),
+            Value::Integer(number) => Ok(number.to_string()),
+            _ => Err(context("values should be strings or integers")),
+        };
+
+        let part = match field("part").and_then(Value::as_integer) {
+            Some(1) => "One",
+            Some(2) => "Two",
+            _ => return Err(context("`part` should be 1 or 2")),
+        };
+        let answer = scalar(field("answer").ok_or_else(|| context("missing `answer`"))?)?;
+        let (input, source) = match (field("file").and_then(Value::as_str), field("input")) {
+            (Some(file), None) => {
+                let path = Path::new(dir).join(file);
+                let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                (
+                    format!("include_str!({:?})", path.display().to_string()),
+                    stem.to_string(),
+                )
+            }
+            (None, Some(Value::String(input))) => (format!("{input:?}"), "inline".to_string()),
+            _ => return Err(context("needs either a `file` or an `input` string")),
+        };
+        let params = match field("params") {
+            Some(Value::Table(params)) => params
+                .iter()
+                .map(|(key, value)| Ok(format!("({key:?}, {:?})", scalar(value)?)))
+                .collect::<Result<Vec<_>, String>>()?
+                .join(", "),
+            Some(_) => return Err(context("`params` should be a table")),
+            None => String::new(),
+        };
+
+        let default_name = format!("part_{}_{}", if part == "One" { 1 } else { 2 }, source);
+        let name = field("name")
+            .and_then(Value::as_str)
+            .unwrap_or(&default_name)
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+            .to_lowercase();
+        if !names.insert(name.clone()) {
+            return Err(context(&format!(
+                "duplicate name `{name}`, set `name` to tell them apart"
+            )));
+        }
+
+        tests.push_str(&format!(
+            "#[test]\n\
+             fn {name}() {{\n    \
+                 let answer = aoc_core::Solution::example(\n        \
+                     &crate::{day},\n        \
+                     {input},\n        \
+                     aoc_core::Part::{part},\n        \
+                     aoc_core::Params(&[{params}]),\n    \
+                 );\n    \
+                 assert_eq!(answer.unwrap(), {answer:?});\n\
+             }}\n\n"
+        ));
+    }
+    Ok(tests)
+}
+
+#[cfg(all(test, feature = "examples"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_tests() {
+        let manifest = "[[example]]\nfile = \"input-test.txt\"\npart = 2\nanswer = 31\n\n\
+                        [[example]]\nname = \"six blinks\"\ninput = \"125 17\"\npart = 1\n\
+                        params = { blinks = 6 }\nanswer = \"22\"\n";
+        let tests = render(manifest, "/aoc/day-11", "Day11").unwrap();
+        assert!(tests.contains("fn part_2_input_test()"));
+        assert!(tests.contains("include_str!(\"/aoc/day-11/input-test.txt\")"));
+        assert!(tests.contains("fn six_blinks()"));
+        assert!(tests.contains("aoc_core::Params(&[(\"blinks\", \"6\")])"));
+        assert!(render("[[example]]\npart = 3\nanswer = 1\n", "/", "Day01").is_err());
+    }
+}
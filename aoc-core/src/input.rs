@@ -0,0 +1,110 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    Directory(PathBuf),
+}
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error(
+        "No input for day {day}: could not read {} ({source}). \
+         Pass a file path, `-` for stdin, or point {INPUT_DIR_VAR} at your inputs",
+        path.display()
+    )]
+    Missing {
+        day: u8,
+        path: PathBuf,
+        source: io::Error,
+    },
+    #[error("Could not read {}: {source}", path.display())]
+    File { path: PathBuf, source: io::Error },
+    #[error("Could not read stdin: {0}")]
+    Stdin(#[source] io::Error),
+}
+
+/// Where a day's input lives inside an inputs directory, `day-XX/input.txt`
+/// like in this workspace.
+pub fn day_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day-{day:02}")).join("input.txt")
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(path.into()),
+        }
+    }
+
+    /// The inputs directory from `AOC_INPUT_DIR`, or the current directory.
+    pub fn from_env() -> Self {
+        InputSource::Directory(env::var_os(INPUT_DIR_VAR).unwrap_or(".".into()).into())
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::Path(path) => {
+                fs::read_to_string(path).map_err(|source| InputError::File {
+                    path: path.clone(),
+                    source,
+                })
+            }
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+            InputSource::Directory(dir) => {
+                let path = day_path(dir, day);
+                fs::read_to_string(&path).map_err(|source| InputError::Missing {
+                    day,
+                    path,
+                    source,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_from_inputs_directory() {
+        let dir = env::temp_dir().join(format!("aoc-core-input-{}", std::process::id()));
+        fs::create_dir_all(day_path(&dir, 3).parent().unwrap()).unwrap();
+        fs::write(day_path(&dir, 3), "mul(2,3)").unwrap();
+
+        let source = InputSource::Directory(dir.clone());
+        assert_eq!(source.read(3).unwrap(), "mul(2,3)");
+        assert!(matches!(
+            source.read(4),
+            Err(InputError::Missing { day: 4, .. })
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn dash_means_stdin() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("day-01/input.txt"),
+            InputSource::Path("day-01/input.txt".into())
+        );
+    }
+}
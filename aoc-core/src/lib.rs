@@ -0,0 +1,282 @@
+mod bench;
+mod config;
+mod examples;
+mod input;
+mod memory;
+mod position;
+mod trace;
+
+use std::{
+    env, fmt,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "criterion")]
+pub use bench::criterion;
+pub use bench::{BenchResult, Timings};
+pub use config::{Config, Params, parse_param, unknown_param};
+#[cfg(feature = "examples")]
+pub use examples::generate;
+pub use input::{INPUT_DIR_VAR, InputError, InputSource, day_path};
+pub use memory::{AllocStats, CountingAllocator, measure};
+pub use position::Position;
+pub use trace::{LOG_VAR, init_tracing};
+
+pub type Answer = String;
+
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("There is no part {value}")),
+        }
+    }
+}
+
+/// The answer to one part, with the time it took to solve it from the parsed
+/// input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// A single day of the calendar. Parsing is done once and the parsed input is
+/// shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// `()` for the days that take no parameters.
+    type Config: Config;
+
+    /// Parses the input. The parts only see the config through what this
+    /// keeps of it in the parsed input.
+    fn parse(&self, input: &str, config: &Self::Config) -> Result<Self::Input, Self::Error>;
+
+    /// Fails when the input has no answer, rather than making one up.
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error>;
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error>;
+
+    /// Solves one part of an example, with its parameters applied to the
+    /// default config.
+    fn example(&self, input: &str, part: Part, params: Params) -> Result<Answer, Error> {
+        let config = Self::Config::from_params(params)?;
+        solve(self, &self.parse(input, &config)?, part)
+    }
+}
+
+/// Object safe view of a [`Solution`], so that the runner can keep every day in
+/// one list.
+pub trait Runnable: Sync {
+    fn day(&self) -> u8;
+
+    fn run(&self, input: &str, parts: &[Part], params: Params) -> Result<Vec<PartResult>, Error>;
+
+    fn bench(
+        &self,
+        input: &str,
+        parts: &[Part],
+        params: Params,
+        iterations: usize,
+    ) -> Result<BenchResult, Error>;
+}
+
+impl<S: Solution + Sync> Runnable for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, parts: &[Part], params: Params) -> Result<Vec<PartResult>, Error> {
+        let _day = tracing::info_span!("day", day = S::DAY).entered();
+        let config = S::Config::from_params(params)?;
+        let input = tracing::info_span!("parse").in_scope(|| self.parse(input, &config))?;
+        parts
+            .iter()
+            .map(|part| {
+                let _part = tracing::info_span!("part", part = %part).entered();
+                let start = Instant::now();
+                let answer = solve(self, &input, *part)?;
+                Ok(PartResult {
+                    part: *part,
+                    answer,
+                    elapsed: start.elapsed(),
+                })
+            })
+            .collect()
+    }
+
+    fn bench(
+        &self,
+        input: &str,
+        parts: &[Part],
+        params: Params,
+        iterations: usize,
+    ) -> Result<BenchResult, Error> {
+        bench::bench(
+            self,
+            input,
+            parts,
+            &S::Config::from_params(params)?,
+            iterations,
+        )
+    }
+}
+
+fn solve<S: Solution + ?Sized>(
+    solution: &S,
+    input: &S::Input,
+    part: Part,
+) -> Result<Answer, Error> {
+    match part {
+        Part::One => solution.part_1(input),
+        Part::Two => solution.part_2(input),
+    }
+    .map_err(|e| format!("part {part}: {e}").into())
+}
+
+pub fn print_results(results: &[PartResult]) {
+    for result in results {
+        println!("Part {}: {}", result.part, result.answer);
+    }
+}
+
+/// Entry point for the per-day binaries. The input is read from the path given
+/// as the first argument, from stdin when it is `-`, or from the inputs
+/// directory otherwise. Logging is only controlled through `AOC_LOG`.
+pub fn main<S: Solution + Sync>(solution: &S) -> ExitCode {
+    init_tracing(false);
+    let source = match env::args().nth(1) {
+        Some(arg) => InputSource::from_arg(&arg),
+        None => InputSource::from_env(),
+    };
+
+    let results = source
+        .read(S::DAY)
+        .map_err(Error::from)
+        .and_then(|input| solution.run(&input, &Part::ALL, Params::default()));
+
+    match results {
+        Ok(results) => {
+            print_results(&results);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Day {}: {e}", S::DAY);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::ParseIntError;
+
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<u64>;
+
+        type Error = ParseIntError;
+
+        type Config = ();
+
+        fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+            input.split_whitespace().map(|v| v.parse()).collect()
+        }
+
+        fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+            Ok(input.iter().sum::<u64>().to_string())
+        }
+
+        fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+            input
+                .iter()
+                .try_fold(1u64, |product, value| product.checked_mul(*value))
+                .map(|product| product.to_string())
+                .ok_or_else(|| "the product overflows".into())
+        }
+    }
+
+    #[test]
+    fn runs_requested_parts() {
+        let results = Sum.run("2 3 4", &[Part::Two], Params::default()).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            (results[0].part, results[0].answer.as_str()),
+            (Part::Two, "24")
+        );
+        assert_eq!(
+            Sum.run("2 3 4", &Part::ALL, Params::default())
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        assert!(Sum.run("2 three 4", &Part::ALL, Params::default()).is_err());
+        assert!(
+            Sum.run("2 3 4", &Part::ALL, Params(&[("size", "7")]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn reports_parts_without_an_answer() {
+        let error = Sum
+            .run(&format!("{} 2", u64::MAX), &[Part::Two], Params::default())
+            .unwrap_err();
+        assert_eq!(error.to_string(), "part 2: the product overflows");
+    }
+
+    #[test]
+    fn part_from_number() {
+        assert_eq!(Part::try_from(1), Ok(Part::One));
+        assert!(Part::try_from(3).is_err());
+    }
+}
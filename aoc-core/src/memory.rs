@@ -0,0 +1,133 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting allocations and the bytes in use. Only a
+/// binary should make it the global allocator, like `aoc` does with its
+/// `alloc-stats` feature.
+pub struct CountingAllocator;
+
+fn grow(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            match new_size.checked_sub(layout.size()) {
+                Some(added) => grow(added),
+                None => {
+                    CURRENT.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+                }
+            }
+        }
+        new_ptr
+    }
+}
+
+/// What one run of a closure allocated. The counters are shared by every
+/// thread, so this is only accurate when nothing else runs at the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocStats {
+    /// Allocations and reallocations.
+    pub allocations: usize,
+    /// Most bytes in use at once, on top of what was in use before.
+    pub peak_bytes: usize,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} allocations, peak ", self.allocations)?;
+        let mut size = self.peak_bytes as f64;
+        for unit in ["B", "KiB", "MiB"] {
+            if size < 1024.0 {
+                return match unit {
+                    "B" => write!(f, "{size} {unit}"),
+                    _ => write!(f, "{size:.1} {unit}"),
+                };
+            }
+            size /= 1024.0;
+        }
+        write!(f, "{size:.1} GiB")
+    }
+}
+
+/// Runs `f` and counts what it allocates, or only runs it when the counting
+/// allocator is not installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    // The runtime allocates before `main`, so nothing counted yet means some
+    // other allocator is in use.
+    if ALLOCATIONS.load(Ordering::SeqCst) == 0 {
+        return (f(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::SeqCst);
+    let base = CURRENT.load(Ordering::SeqCst);
+    PEAK.store(base, Ordering::SeqCst);
+
+    let value = f();
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::SeqCst) - allocations,
+        peak_bytes: PEAK.load(Ordering::SeqCst).saturating_sub(base),
+    };
+    (value, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn formats_sizes() {
+        let stats = |peak_bytes| AllocStats {
+            allocations: 3,
+            peak_bytes,
+        };
+        assert_eq!(stats(512).to_string(), "3 allocations, peak 512 B");
+        assert_eq!(stats(1536).to_string(), "3 allocations, peak 1.5 KiB");
+        assert_eq!(stats(3 << 30).to_string(), "3 allocations, peak 3.0 GiB");
+    }
+
+    #[test]
+    fn counts_allocations() {
+        let (_, stats) = measure(|| Vec::<u8>::with_capacity(4096));
+        let stats = stats.unwrap();
+        assert!(stats.allocations >= 1);
+        assert!(stats.peak_bytes >= 4096);
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt;
+
+/// A 1-based line and column in a puzzle input, for pointing at the text a
+/// parser choked on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// From 0-based indices, as given by `enumerate`.
+    pub fn new(line_index: usize, column_index: usize) -> Self {
+        Position {
+            line: line_index + 1,
+            column: column_index + 1,
+        }
+    }
+
+    /// Position of `fragment`, which has to be a slice of `line`.
+    pub fn of(line_index: usize, line: &str, fragment: &str) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .saturating_sub(line.as_ptr() as usize)
+            .min(line.len());
+        let column_index = line
+            .get(..offset)
+            .map(|before| before.chars().count())
+            .unwrap_or(offset);
+        Position::new(line_index, column_index)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_of_fragment() {
+        let line = "190: 10 x9";
+        let fragment = line.split_whitespace().last().unwrap();
+        assert_eq!(
+            Position::of(4, line, fragment),
+            Position { line: 5, column: 9 }
+        );
+        assert_eq!(
+            Position::of(4, line, fragment).to_string(),
+            "line 5, column 9"
+        );
+    }
+}
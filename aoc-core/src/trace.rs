@@ -0,0 +1,46 @@
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+
+use tracing_subscriber::{EnvFilter, fmt::format::FmtSpan};
+
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// Prints spans and progress events to stderr, with the time spent in each
+/// span when it closes. `AOC_LOG` takes `RUST_LOG` style directives such as
+/// `day_20=trace`; without it `verbose` turns on debug output everywhere.
+pub fn init_tracing(verbose: bool) {
+    let Some(filter) = filter(env::var(LOG_VAR).ok().as_deref(), verbose) else {
+        return;
+    };
+
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_span_events(FmtSpan::CLOSE)
+        .try_init();
+}
+
+fn filter(directives: Option<&str>, verbose: bool) -> Option<EnvFilter> {
+    match directives {
+        Some(directives) => Some(EnvFilter::new(directives)),
+        None => verbose.then(|| EnvFilter::new("debug")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn silent_by_default() {
+        assert!(filter(None, false).is_none());
+        assert_eq!(filter(None, true).unwrap().to_string(), "debug");
+        assert_eq!(
+            filter(Some("day_06=trace"), false).unwrap().to_string(),
+            "day_06=trace"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use aoc_core::Part;
+use serde::Serialize;
+use toml::{Table, Value};
+
+/// Expected answers for the real inputs, read from a file like
+///
+/// ```toml
+/// [day-01]
+/// part-1 = 2113135
+/// part-2 = "19097157"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    expected: HashMap<(u8, Part), String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    Unknown,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+        Self::parse(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table: Table = text
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+        let mut expected = HashMap::new();
+
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day-")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or(format!("Expected a `[day-XX]` table, found `{day_key}`"))?;
+            let parts = parts
+                .as_table()
+                .ok_or(format!("`{day_key}` should be a table of parts"))?;
+
+            for (part_key, answer) in parts {
+                let part = part_key
+                    .strip_prefix("part-")
+                    .and_then(|part| part.parse::<u8>().ok())
+                    .and_then(|part| Part::try_from(part).ok())
+                    .ok_or(format!(
+                        "Expected `part-1` or `part-2` in `{day_key}`, found `{part_key}`"
+                    ))?;
+                let answer = match answer {
+                    Value::String(answer) => answer.clone(),
+                    Value::Integer(answer) => answer.to_string(),
+                    _ => {
+                        return Err(format!(
+                            "`{day_key}.{part_key}` should be a string or an integer"
+                        ));
+                    }
+                };
+                expected.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { expected })
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        match self.expected.get(&(day, part)) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(_) => Verdict::Wrong,
+            None => Verdict::Unknown,
+        }
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        self.expected.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers::parse("[day-01]\npart-1 = 11\npart-2 = \"31\"\n").unwrap();
+        assert_eq!(answers.check(1, Part::One, "11"), Verdict::Correct);
+        assert_eq!(answers.check(1, Part::Two, "30"), Verdict::Wrong);
+        assert_eq!(answers.check(2, Part::One, "2"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(Answers::parse("[day-01]\npart-3 = 1\n").is_err());
+        assert!(Answers::parse("[one]\npart-1 = 1\n").is_err());
+        assert!(Answers::parse("[day-01]\npart-1 = 1.5\n").is_err());
+    }
+}
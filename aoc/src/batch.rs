@@ -0,0 +1,231 @@
+use std::{
+    any::Any,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::Duration,
+};
+
+use aoc_core::{Params, Part, PartResult, Runnable};
+use rayon::prelude::*;
+
+/// The answers for one input file, or why there are none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub input: String,
+    pub results: Result<Vec<PartResult>, String>,
+}
+
+/// Solves every file in `dir` in parallel, returning the rows in file name
+/// order. Hidden files, like the session cookie, are skipped. A solver that
+/// panics only fails the row of its input.
+pub fn run(
+    day: &dyn Runnable,
+    dir: &Path,
+    parts: &[Part],
+    params: Params,
+) -> Result<Vec<Row>, String> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("Could not read {}: {e}", dir.display()))?;
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .filter(|path| !file_name(path).starts_with('.'))
+        .collect::<Vec<_>>();
+    if paths.is_empty() {
+        return Err(format!("No inputs in {}", dir.display()));
+    }
+    paths.sort();
+
+    Ok(paths
+        .par_iter()
+        .map(|path| {
+            let results = fs::read_to_string(path)
+                .map_err(|e| format!("Could not read {}: {e}", path.display()))
+                .and_then(|input| solve(day, &input, parts, params));
+            Row {
+                input: file_name(path),
+                results,
+            }
+        })
+        .collect())
+}
+
+fn solve(
+    day: &dyn Runnable,
+    input: &str,
+    parts: &[Part],
+    params: Params,
+) -> Result<Vec<PartResult>, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| day.run(input, parts, params)))
+        .map_err(|payload| format!("panicked: {}", panic_message(&*payload)))?
+        .map_err(|e| e.to_string())
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload
+            .downcast_ref::<String>()
+            .map_or("unknown cause", String::as_str),
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Lines up the answers in one column per part, with the time it took to solve
+/// each input.
+pub fn table(rows: &[Row], parts: &[Part]) -> String {
+    let mut header = vec!["Input".to_string()];
+    header.extend(parts.iter().map(|part| format!("Part {part}")));
+    header.push("Time".to_string());
+
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            let mut cells = vec![row.input.clone()];
+            match &row.results {
+                Ok(results) => {
+                    cells.extend(results.iter().map(|result| result.answer.clone()));
+                    let elapsed = results
+                        .iter()
+                        .map(|result| result.elapsed)
+                        .sum::<Duration>();
+                    cells.push(format!("{elapsed:.1?}"));
+                }
+                Err(e) => cells.push(format!("error: {e}")),
+            }
+            cells
+        })
+        .collect();
+
+    // Errors are left out of the widths so that they do not stretch the table.
+    let mut widths: Vec<_> = header.iter().map(|cell| cell.chars().count()).collect();
+    for (_, row) in rows
+        .iter()
+        .zip(&cells)
+        .filter(|(row, _)| row.results.is_ok())
+    {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    widths[0] = cells
+        .iter()
+        .map(|row| row[0].chars().count())
+        .fold(widths[0], usize::max);
+
+    let mut text = String::new();
+    for row in std::iter::once(&header).chain(&cells) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_every_input() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("bob.txt"), "1 2\n3 x\n").unwrap();
+        fs::write(dir.path().join("alice.txt"), "3 4\n4 3\n2 5\n").unwrap();
+        fs::write(dir.path().join(".aoc-session"), "secret").unwrap();
+
+        let rows = run(&day_01::Day01, dir.path(), &Part::ALL, Params::default()).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].input, "alice.txt");
+        let answers: Vec<_> = rows[0]
+            .results
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|r| r.answer.as_str())
+            .collect();
+        assert_eq!(answers, ["3", "7"]);
+        assert_eq!(rows[1].input, "bob.txt");
+        assert!(rows[1].results.is_err());
+    }
+
+    struct Panics;
+
+    impl Runnable for Panics {
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn run(
+            &self,
+            input: &str,
+            _: &[Part],
+            _: Params,
+        ) -> Result<Vec<PartResult>, aoc_core::Error> {
+            match input {
+                "" => Ok(vec![]),
+                _ => panic!("cannot solve `{input}`"),
+            }
+        }
+
+        fn bench(
+            &self,
+            _: &str,
+            _: &[Part],
+            _: Params,
+            _: usize,
+        ) -> Result<aoc_core::BenchResult, aoc_core::Error> {
+            Err("not benchmarked".into())
+        }
+    }
+
+    #[test]
+    fn keeps_going_after_a_panic() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("empty.txt"), "").unwrap();
+        fs::write(dir.path().join("bad.txt"), "x").unwrap();
+
+        let rows = run(&Panics, dir.path(), &Part::ALL, Params::default()).unwrap();
+        assert_eq!(
+            rows[0].results,
+            Err("panicked: cannot solve `x`".to_string())
+        );
+        assert_eq!(rows[1].results, Ok(vec![]));
+    }
+
+    #[test]
+    fn lines_up_answers() {
+        let result = |part, answer: &str| PartResult {
+            part,
+            answer: answer.to_string(),
+            elapsed: Duration::from_millis(2),
+        };
+        let rows = [
+            Row {
+                input: "alice.txt".to_string(),
+                results: Ok(vec![result(Part::One, "11"), result(Part::Two, "31")]),
+            },
+            Row {
+                input: "björn.txt".to_string(),
+                results: Err("invalid number `x` at line 2, column 3".to_string()),
+            },
+        ];
+        assert_eq!(
+            table(&rows, &Part::ALL),
+            "Input      Part 1  Part 2  Time\n\
+             alice.txt  11      31      4.0ms\n\
+             björn.txt  error: invalid number `x` at line 2, column 3\n"
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::{env, fs, path::Path};
+
+use aoc_core::Part;
+use ureq::Agent;
+
+pub const YEAR: u16 = 2024;
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Talks to the Advent of Code website, or to anything that serves the same
+/// paths under `base_url`.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(concat!("aoc-2024-runner/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .into();
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{YEAR}/day/{day}{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let mut response = self
+            .agent
+            .get(self.url(day, "/input"))
+            .header("Cookie", self.cookie())
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::StatusCode(404) => format!("Day {day} is not unlocked yet"),
+                ureq::Error::StatusCode(400 | 500) => {
+                    format!("Could not fetch day {day}: is the session token still valid?")
+                }
+                e => format!("Could not fetch day {day}: {e}"),
+            })?;
+        response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| format!("Could not read the input for day {day}: {e}"))
+    }
+
+    /// Posts an answer and returns the page the site replies with.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<String, String> {
+        let level = u8::from(part).to_string();
+        let mut response = self
+            .agent
+            .post(self.url(day, "/answer"))
+            .header("Cookie", self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .map_err(|e| format!("Could not submit day {day} part {part}: {e}"))?;
+        response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| format!("Could not read the response for day {day}: {e}"))
+    }
+}
+
+/// The session token from `AOC_SESSION`, or from a `.aoc-session` file in the
+/// inputs directory.
+pub fn session(inputs_dir: &Path) -> Result<String, String> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        return Ok(session);
+    }
+    let path = inputs_dir.join(".aoc-session");
+    fs::read_to_string(&path).map_err(|_| {
+        format!(
+            "No session token: set {SESSION_VAR} or put it in {}",
+            path.display()
+        )
+    })
+}
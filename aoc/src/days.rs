@@ -0,0 +1,143 @@
+use std::{fmt::Display, io::BufRead};
+
+use aoc_core::{Config, Params, Part, Runnable};
+use day_01::BigInt;
+
+pub static DAYS: [&dyn Runnable; 20] = [
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+    &day_17::Day17,
+    &day_18::Day18,
+    &day_19::Day19,
+    &day_20::Day20,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Runnable> {
+    DAYS.iter().find(|d| d.day() == day).copied()
+}
+
+/// Solves a day while reading its input, for the days that can, so that
+/// inputs too large to load can still be checked.
+pub fn stream(
+    day: u8,
+    reader: impl BufRead,
+    params: Params,
+) -> Option<Result<[String; 2], String>> {
+    let answers = match day {
+        1 => config::<()>(params).and_then(|()| answers(day_01(reader))),
+        2 => config(params).and_then(|config| answers(day_02::count_safe(reader, &config))),
+        7 => config::<()>(params).and_then(|()| answers(day_07::calibrate(reader))),
+        19 => config::<()>(params).and_then(|()| answers(day_19::count_possible_from(reader))),
+        _ => return None,
+    };
+    Some(answers)
+}
+
+fn config<C: Config>(params: Params) -> Result<C, String> {
+    C::from_params(params).map_err(|e| e.to_string())
+}
+
+/// Annotates each record of the input with why it passes or fails a part,
+/// for the days that can.
+pub fn explain(day: u8, input: &str, part: Part, params: Params) -> Option<Result<String, String>> {
+    match day {
+        2 => Some(day_02(input, part, params)),
+        _ => None,
+    }
+}
+
+fn day_02(input: &str, part: Part, params: Params) -> Result<String, String> {
+    let config: day_02::Config = config(params)?;
+    let reports = day_02::parse_input(input).map_err(|e| e.to_string())?;
+    let policy = match part {
+        Part::One => config.part_1(),
+        Part::Two => config.part_2(),
+    };
+    Ok(day_02::annotate(&reports, &policy))
+}
+
+/// Big integers, so that generated lists cannot overflow.
+fn day_01(reader: impl BufRead) -> Result<(BigInt, BigInt), String> {
+    let pair = day_01::read_input(reader).map_err(|e| e.to_string())?;
+    let part_1 = day_01::part_1(&pair).map_err(|e| e.to_string())?;
+    let part_2 = day_01::part_2(&pair).map_err(|e| e.to_string())?;
+    Ok((part_1, part_2))
+}
+
+fn answers(
+    result: Result<(impl Display, impl Display), impl Display>,
+) -> Result<[String; 2], String> {
+    result
+        .map(|(part_1, part_2)| [part_1.to_string(), part_2.to_string()])
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn streams_some_days() {
+        let none = Params::default();
+        let answers = stream(
+            1,
+            "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n".as_bytes(),
+            none,
+        );
+        assert_eq!(answers, Some(Ok(["11".to_string(), "31".to_string()])));
+        assert!(matches!(stream(2, "1\n".as_bytes(), none), Some(Err(_))));
+        assert_eq!(stream(3, "".as_bytes(), none), None);
+    }
+
+    #[test]
+    fn streams_with_params() {
+        let input = "7 6 4 2 1\n1 3 2 4 5\n1 2 7 8 9\n".as_bytes();
+        assert_eq!(
+            stream(2, input, Params::default()),
+            Some(Ok(["1".to_string(), "2".to_string()]))
+        );
+        let params = [("trend", "decreasing")];
+        assert_eq!(
+            stream(2, input, Params(&params)),
+            Some(Ok(["1".to_string(), "1".to_string()]))
+        );
+        let params = [("size", "7")];
+        assert!(matches!(
+            stream(7, "".as_bytes(), Params(&params)),
+            Some(Err(_))
+        ));
+    }
+
+    #[test]
+    fn explains_day_2() {
+        let input = "7 6 4 2 1\n1 3 2 4 5\n";
+        assert_eq!(
+            explain(2, input, Part::One, Params::default()),
+            Some(Ok("7 6 4 2 1: safe\n\
+                     1 3 2 4 5: unsafe; levels 2-3 (3, 2): direction flip\n"
+                .to_string()))
+        );
+        let params = [("trend", "decreasing")];
+        assert_eq!(
+            explain(2, input, Part::Two, Params(&params)),
+            Some(Ok("7 6 4 2 1: safe\n\
+                     1 3 2 4 5: unsafe; levels 1-2 (1, 3): wrong direction\n"
+                .to_string()))
+        );
+        assert_eq!(explain(3, "", Part::One, Params::default()), None);
+    }
+}
@@ -0,0 +1,99 @@
+use std::{fs, path::Path};
+
+use aoc_core::day_path;
+
+use crate::client::Client;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+/// Downloads a day's input into the inputs directory, unless it is already
+/// there. Cached inputs are never downloaded again, and `client` is only
+/// called when there is something to download.
+pub fn fetch(
+    inputs_dir: &Path,
+    day: u8,
+    client: impl FnOnce() -> Result<Client, String>,
+) -> Result<Fetched, String> {
+    let path = day_path(inputs_dir, day);
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client()?.input(day)?;
+    let write = |path: &Path| {
+        fs::create_dir_all(path.parent().unwrap_or(inputs_dir))?;
+        fs::write(path, input)
+    };
+    write(&path).map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    #[test]
+    fn downloads_once() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            let url = request.url().to_string();
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string());
+            request
+                .respond(Response::from_string("1 2\n3 4\n"))
+                .unwrap();
+            (url, cookie)
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let client = || Ok(Client::new(&base_url, "secret\n"));
+        assert_eq!(fetch(dir.path(), 1, client), Ok(Fetched::Downloaded));
+        assert_eq!(
+            handle.join().unwrap(),
+            (
+                "/2024/day/1/input".to_string(),
+                Some("session=secret".to_string())
+            )
+        );
+        assert_eq!(
+            fs::read_to_string(day_path(dir.path(), 1)).unwrap(),
+            "1 2\n3 4\n"
+        );
+
+        let offline = || Err("should not download again".to_string());
+        assert_eq!(fetch(dir.path(), 1, offline), Ok(Fetched::Cached));
+    }
+
+    #[test]
+    fn reports_locked_days() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        thread::spawn(move || {
+            let request = server.recv().unwrap();
+            request
+                .respond(Response::from_string("Not found").with_status_code(404))
+                .unwrap();
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let client = || Ok(Client::new(&base_url, "secret"));
+        assert_eq!(
+            fetch(dir.path(), 25, client),
+            Err("Day 25 is not unlocked yet".to_string())
+        );
+        assert!(!day_path(dir.path(), 25).exists());
+    }
+}
@@ -0,0 +1,501 @@
+mod answers;
+mod batch;
+mod client;
+mod days;
+mod fetch;
+mod output;
+mod scaffold;
+mod submit;
+
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use answers::Answers;
+use client::{BASE_URL_VAR, Client, DEFAULT_BASE_URL};
+
+use aoc_core::{INPUT_DIR_VAR, InputSource, Params, Part, Runnable};
+use clap::{Args, Parser, Subcommand};
+use fetch::Fetched;
+use output::{Format, Report};
+use submit::{Log, Outcome};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: aoc_core::CountingAllocator = aoc_core::CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log solver progress and timings to stderr. `AOC_LOG` takes filter
+    /// directives like `day_20=trace` for finer control
+    #[arg(short, long, global = true)]
+    verbose: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day in order when no day is given
+    Run {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        #[command(flatten)]
+        config: ConfigArgs,
+
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Check the answers against this file. Defaults to `answers.toml` in
+        /// the inputs directory, if there is one and no input path is given.
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+    },
+    /// Parse once and time each part over several runs
+    Bench {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        #[command(flatten)]
+        config: ConfigArgs,
+
+        /// How many times to run each part
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+    },
+    /// Solve one day for every input file in a directory, in parallel, and
+    /// print the answers side by side
+    Batch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Directory with one input per file
+        dir: PathBuf,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Solve a day while reading its input one line at a time, for inputs too
+    /// large to load. Only days 1, 2, 7 and 19 support this
+    Stream {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Read the input from this file, or from stdin when `-`
+        path: PathBuf,
+
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Annotate each record of the input with why it passes or fails a part.
+    /// Only day 2 supports this
+    Explain {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(short, long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+    /// Download a day's input into the inputs directory, unless it is already
+    /// there
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Directory with the inputs laid out as `day-XX/input.txt`
+        #[arg(long, env = INPUT_DIR_VAR, default_value = ".")]
+        inputs_dir: PathBuf,
+
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+    /// Create a `day-XX` crate from the template and register it with the
+    /// workspace and the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Root of the workspace
+        #[arg(long, default_value = ".")]
+        workspace: PathBuf,
+    },
+    /// Submit the answer to one part, solving it first when no answer is given
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        answer: Option<String>,
+
+        /// Directory with the inputs laid out as `day-XX/input.txt`, and where
+        /// submissions are recorded
+        #[arg(long, env = INPUT_DIR_VAR, default_value = ".")]
+        inputs_dir: PathBuf,
+
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+}
+
+#[derive(Args)]
+struct ServerArgs {
+    /// Where the puzzles are served from
+    #[arg(long, env = BASE_URL_VAR, default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl ServerArgs {
+    fn client(&self, inputs_dir: &Path) -> Result<Client, String> {
+        Ok(Client::new(&self.base_url, &client::session(inputs_dir)?))
+    }
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the input from this file, or from stdin when `-`
+    #[arg(short, long, requires = "day", value_name = "PATH")]
+    input: Option<String>,
+
+    /// Directory with the inputs laid out as `day-XX/input.txt`
+    #[arg(long, env = INPUT_DIR_VAR, default_value = ".")]
+    inputs_dir: PathBuf,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        match &self.input {
+            Some(arg) => InputSource::from_arg(arg),
+            None => InputSource::Directory(self.inputs_dir.clone()),
+        }
+    }
+}
+
+#[derive(Args)]
+struct ConfigArgs {
+    /// Change one of the day's puzzle constants, like `--set size=7` to solve
+    /// an example
+    #[arg(long = "set", value_name = "KEY=VALUE", value_parser = key_value)]
+    set: Vec<(String, String)>,
+}
+
+impl ConfigArgs {
+    fn pairs(&self) -> Vec<(&str, &str)> {
+        self.set
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect()
+    }
+}
+
+fn key_value(arg: &str) -> Result<(String, String), String> {
+    let (key, value) = arg
+        .split_once('=')
+        .ok_or(format!("expected `KEY=VALUE`, found `{arg}`"))?;
+    Ok((key.trim().to_string(), value.trim().to_string()))
+}
+
+fn run_day(
+    day: &dyn Runnable,
+    parts: &[Part],
+    source: &InputSource,
+    params: Params,
+    report: &mut Report,
+) -> Result<(), String> {
+    let input = source.read(day.day()).map_err(|e| e.to_string())?;
+    let results = day
+        .run(&input, parts, params)
+        .map_err(|e| format!("Day {}: {e}", day.day()))?;
+    report.add(day.day(), &input, results);
+    Ok(())
+}
+
+fn bench_day(
+    day: &dyn Runnable,
+    parts: &[Part],
+    source: &InputSource,
+    params: Params,
+    iterations: usize,
+) -> Result<(), String> {
+    let input = source.read(day.day()).map_err(|e| e.to_string())?;
+    let result = day
+        .bench(&input, parts, params, iterations)
+        .map_err(|e| format!("Day {}: {e}", day.day()))?;
+    println!("Day {}", day.day());
+    result.print();
+    Ok(())
+}
+
+fn parts(part: Option<u8>) -> Result<Vec<Part>, String> {
+    match part {
+        Some(part) => Ok(vec![Part::try_from(part)?]),
+        None => Ok(Part::ALL.to_vec()),
+    }
+}
+
+/// The requested day, or every solved day in order.
+fn days(day: Option<u8>) -> Result<Vec<&'static dyn Runnable>, String> {
+    match day {
+        Some(day) => {
+            let day = days::find(day).ok_or(format!("Day {day} is not solved yet"))?;
+            Ok(vec![day])
+        }
+        None => Ok(days::DAYS.to_vec()),
+    }
+}
+
+fn load_answers(path: Option<&PathBuf>, input: &InputArgs) -> Result<Option<Answers>, String> {
+    if let Some(path) = path {
+        return Answers::load(path).map(Some);
+    }
+    if input.input.is_some() {
+        return Ok(None);
+    }
+
+    let path = input.inputs_dir.join("answers.toml");
+    if path.exists() {
+        Answers::load(&path).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn run(
+    day: Option<u8>,
+    part: Option<u8>,
+    input: &InputArgs,
+    config: &ConfigArgs,
+    format: Format,
+    answers: Option<&PathBuf>,
+) -> Result<(), String> {
+    let parts = parts(part)?;
+    let source = input.source();
+    let params = config.pairs();
+    let mut report = Report::new(format, load_answers(answers, input)?);
+
+    for day in days(day)? {
+        run_day(day, &parts, &source, Params(&params), &mut report)?;
+    }
+
+    let wrong = report.wrong();
+    report.finish(io::stdout()).map_err(|e| e.to_string())?;
+    match wrong {
+        0 => Ok(()),
+        1 => Err("1 answer does not match the answers file".to_string()),
+        n => Err(format!("{n} answers do not match the answers file")),
+    }
+}
+
+fn bench(
+    day: Option<u8>,
+    part: Option<u8>,
+    input: &InputArgs,
+    config: &ConfigArgs,
+    iterations: u32,
+) -> Result<(), String> {
+    let parts = parts(part)?;
+    let source = input.source();
+    let params = config.pairs();
+
+    days(day)?
+        .into_iter()
+        .try_for_each(|day| bench_day(day, &parts, &source, Params(&params), iterations as usize))
+}
+
+fn batch(day: u8, dir: &Path, part: Option<u8>, config: &ConfigArgs) -> Result<(), String> {
+    let parts = parts(part)?;
+    let solution = days::find(day).ok_or(format!("Day {day} is not solved yet"))?;
+    let params = config.pairs();
+    let rows = batch::run(solution, dir, &parts, Params(&params))?;
+    print!("{}", batch::table(&rows, &parts));
+
+    match rows.iter().filter(|row| row.results.is_err()).count() {
+        0 => Ok(()),
+        1 => Err("1 input could not be solved".to_string()),
+        n => Err(format!("{n} inputs could not be solved")),
+    }
+}
+
+fn stream(day: u8, path: &Path, config: &ConfigArgs) -> Result<(), String> {
+    let params = config.pairs();
+    let params = Params(&params);
+    let answers = match path == Path::new("-") {
+        true => days::stream(day, io::stdin().lock(), params),
+        false => {
+            let file =
+                File::open(path).map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+            days::stream(day, BufReader::new(file), params)
+        }
+    };
+    let answers = answers.ok_or(format!("Day {day} cannot read its input as a stream"))??;
+
+    println!("Day {day}");
+    for (part, answer) in Part::ALL.iter().zip(answers) {
+        println!("Part {part}: {answer}");
+    }
+    Ok(())
+}
+
+fn explain(day: u8, part: u8, input: &InputArgs, config: &ConfigArgs) -> Result<(), String> {
+    let part = Part::try_from(part)?;
+    let input = input.source().read(day).map_err(|e| e.to_string())?;
+    let params = config.pairs();
+    let annotated = days::explain(day, &input, part, Params(&params))
+        .ok_or(format!("Day {day} cannot explain its answers"))?
+        .map_err(|e| format!("Day {day}: {e}"))?;
+    print!("{annotated}");
+    Ok(())
+}
+
+fn fetch(day: u8, inputs_dir: &Path, server: &ServerArgs) -> Result<(), String> {
+    let path = aoc_core::day_path(inputs_dir, day);
+    match fetch::fetch(inputs_dir, day, || server.client(inputs_dir))? {
+        Fetched::Downloaded => println!("Day {day}: saved to {}", path.display()),
+        Fetched::Cached => println!("Day {day}: already in {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(
+    day: u8,
+    part: u8,
+    answer: Option<&String>,
+    inputs_dir: &Path,
+    server: &ServerArgs,
+) -> Result<(), String> {
+    let part = Part::try_from(part)?;
+    let answer = match answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let solution = days::find(day).ok_or(format!("Day {day} is not solved yet"))?;
+            let input = InputSource::Directory(inputs_dir.to_path_buf())
+                .read(day)
+                .map_err(|e| e.to_string())?;
+            let results = solution
+                .run(&input, &[part], Params::default())
+                .map_err(|e| format!("Day {day}: {e}"))?;
+            results[0].answer.clone()
+        }
+    };
+
+    let path = inputs_dir.join(".aoc-submissions.json");
+    let mut log = Log::load(&path)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs();
+    let outcome = submit::submit(
+        &server.client(inputs_dir)?,
+        &mut log,
+        day,
+        part,
+        &answer,
+        now,
+    );
+    log.save(&path)?;
+
+    match outcome? {
+        Outcome::Correct => println!("Day {day} part {part}: {answer} is correct"),
+        Outcome::TooHigh => println!("Day {day} part {part}: {answer} is too high"),
+        Outcome::TooLow => println!("Day {day} part {part}: {answer} is too low"),
+        Outcome::Wrong => println!("Day {day} part {part}: {answer} is wrong"),
+        Outcome::AlreadySolved => println!("Day {day} part {part} is already solved"),
+        Outcome::Wait { seconds } => {
+            return Err(format!("Submitted too soon, wait {seconds}s and try again"));
+        }
+    }
+    Ok(())
+}
+
+fn new(day: u8, workspace: &Path) -> Result<(), String> {
+    for path in scaffold::create(workspace, day)? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc_core::init_tracing(cli.verbose);
+    let result = match &cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            config,
+            format,
+            answers,
+        } => run(*day, *part, input, config, *format, answers.as_ref()),
+        Command::Bench {
+            day,
+            part,
+            input,
+            config,
+            iterations,
+        } => bench(*day, *part, input, config, *iterations),
+        Command::Batch {
+            day,
+            dir,
+            part,
+            config,
+        } => batch(*day, dir, *part, config),
+        Command::Stream { day, path, config } => stream(*day, path, config),
+        Command::Explain {
+            day,
+            part,
+            input,
+            config,
+        } => explain(*day, *part, input, config),
+        Command::Fetch {
+            day,
+            inputs_dir,
+            server,
+        } => fetch(*day, inputs_dir, server),
+        Command::New { day, workspace } => new(*day, workspace),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            inputs_dir,
+            server,
+        } => submit(*day, *part, answer.as_ref(), inputs_dir, server),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
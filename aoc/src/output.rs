@@ -0,0 +1,201 @@
+use std::io::{self, Write};
+
+use aoc_core::PartResult;
+use clap::ValueEnum;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::answers::{Answers, Verdict};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// `Part N: answer` lines under a `Day N` header
+    #[default]
+    Text,
+    /// A JSON array of records
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+/// One answer, with enough context to compare it with other runs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub elapsed_ms: f64,
+    /// SHA-256 of the puzzle input, so answers to different inputs are not
+    /// mixed up.
+    pub input_hash: String,
+    /// Only set when checking against an answers file.
+    pub verdict: Option<Verdict>,
+}
+
+pub fn input_hash(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+/// Prints text as soon as a day is done, and collects records for the
+/// structured formats until [`Report::finish`].
+pub struct Report {
+    format: Format,
+    answers: Option<Answers>,
+    records: Vec<Record>,
+    wrong: usize,
+}
+
+impl Report {
+    pub fn new(format: Format, answers: Option<Answers>) -> Self {
+        Report {
+            format,
+            answers,
+            records: vec![],
+            wrong: 0,
+        }
+    }
+
+    pub fn add(&mut self, day: u8, input: &str, results: Vec<PartResult>) {
+        let verdicts: Vec<_> = results
+            .iter()
+            .map(|result| {
+                let answers = self.answers.as_ref()?;
+                Some(answers.check(day, result.part, &result.answer))
+            })
+            .collect();
+        self.wrong += verdicts
+            .iter()
+            .filter(|verdict| **verdict == Some(Verdict::Wrong))
+            .count();
+
+        if self.format == Format::Text {
+            println!("Day {day}");
+            for (result, verdict) in results.iter().zip(&verdicts) {
+                println!(
+                    "Part {}: {}{}",
+                    result.part,
+                    result.answer,
+                    self.note(day, result, verdict.as_ref())
+                );
+            }
+            return;
+        }
+
+        let input_hash = input_hash(input);
+        self.records.extend(
+            results
+                .into_iter()
+                .zip(verdicts)
+                .map(|(result, verdict)| Record {
+                    day,
+                    part: result.part.into(),
+                    answer: result.answer,
+                    elapsed_ms: result.elapsed.as_secs_f64() * 1000.0,
+                    input_hash: input_hash.clone(),
+                    verdict,
+                }),
+        );
+    }
+
+    fn note(&self, day: u8, result: &PartResult, verdict: Option<&Verdict>) -> String {
+        match verdict {
+            None => String::new(),
+            Some(Verdict::Correct) => " (correct)".to_string(),
+            Some(Verdict::Wrong) => {
+                let expected = self
+                    .answers
+                    .as_ref()
+                    .and_then(|answers| answers.expected(day, result.part))
+                    .unwrap_or_default();
+                format!(" (wrong, expected {expected})")
+            }
+            Some(Verdict::Unknown) => " (unknown)".to_string(),
+        }
+    }
+
+    /// How many answers did not match the answers file.
+    pub fn wrong(&self) -> usize {
+        self.wrong
+    }
+
+    pub fn finish(self, mut out: impl Write) -> io::Result<()> {
+        match self.format {
+            Format::Text => Ok(()),
+            Format::Json => {
+                serde_json::to_writer_pretty(&mut out, &self.records)?;
+                writeln!(out)
+            }
+            Format::Csv => {
+                let mut writer = csv::Writer::from_writer(out);
+                for record in &self.records {
+                    writer.serialize(record)?;
+                }
+                writer.flush()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_core::Part;
+
+    use super::*;
+
+    fn result(part: Part, answer: &str) -> PartResult {
+        PartResult {
+            part,
+            answer: answer.to_string(),
+            elapsed: Duration::from_micros(1500),
+        }
+    }
+
+    fn report(format: Format) -> String {
+        let mut report = Report::new(format, None);
+        report.add(18, "5,4\n", vec![result(Part::Two, "6,1")]);
+
+        let mut out = vec![];
+        report.finish(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn writes_json() {
+        let records: serde_json::Value = serde_json::from_str(&report(Format::Json)).unwrap();
+        assert_eq!(records[0]["day"], 18);
+        assert_eq!(records[0]["part"], 2);
+        assert_eq!(records[0]["answer"], "6,1");
+        assert_eq!(records[0]["elapsed_ms"], 1.5);
+        assert_eq!(records[0]["input_hash"], input_hash("5,4\n"));
+    }
+
+    #[test]
+    fn writes_csv() {
+        let csv = report(Format::Csv);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("day,part,answer,elapsed_ms,input_hash,verdict")
+        );
+        assert_eq!(
+            lines.next(),
+            Some(format!("18,2,\"6,1\",1.5,{},", input_hash("5,4\n")).as_str())
+        );
+    }
+
+    #[test]
+    fn counts_wrong_answers() {
+        let answers = Answers::parse("[day-18]\npart-1 = 22\npart-2 = \"6,1\"\n").unwrap();
+        let mut report = Report::new(Format::Json, Some(answers));
+        report.add(
+            18,
+            "",
+            vec![result(Part::One, "21"), result(Part::Two, "6,1")],
+        );
+        assert_eq!(report.wrong(), 1);
+        assert_eq!(report.records[0].verdict, Some(Verdict::Wrong));
+        assert_eq!(report.records[1].verdict, Some(Verdict::Correct));
+    }
+}
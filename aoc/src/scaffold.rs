@@ -0,0 +1,207 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATES: [(&str, &str); 6] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("build.rs", include_str!("../templates/build.rs.tmpl")),
+    (
+        "examples.toml",
+        include_str!("../templates/examples.toml.tmpl"),
+    ),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    (
+        "benches/day_{{day_02}}.rs",
+        include_str!("../templates/bench.rs.tmpl"),
+    ),
+];
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{day_02}}", &format!("{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Creates `day-XX` from the templates, adds it to the workspace members and
+/// registers it with the runner. Returns the files that were written.
+pub fn create(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day-{day:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Check every file we have to edit before writing anything.
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc/Cargo.toml");
+    let days = root.join("aoc/src/days.rs");
+    let edited = [
+        (&workspace, add_member(&read(&workspace)?, &name)?),
+        (
+            &runner,
+            insert_sorted(
+                &read(&runner)?,
+                "day-",
+                &format!("{name} = {{ path = \"../{name}\" }}"),
+            )?,
+        ),
+        (&days, register_day(&read(&days)?, day)?),
+    ];
+
+    let mut written = vec![];
+    for (path, template) in TEMPLATES {
+        let path = dir.join(render(path, day));
+        write(&path, &render(template, day))?;
+        written.push(path);
+    }
+    let example = dir.join("input-test.txt");
+    write(&example, "")?;
+    written.push(example);
+
+    for (path, text) in edited {
+        write(path, &text)?;
+        written.push(path.clone());
+    }
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Could not read {}: {e}", path.display()))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    let parent = path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(parent)
+        .and_then(|_| fs::write(path, text))
+        .map_err(|e| format!("Could not write {}: {e}", path.display()))
+}
+
+/// Adds `name` to the single line `members = [...]` list, keeping the days in
+/// order after the other crates.
+fn add_member(manifest: &str, name: &str) -> Result<String, String> {
+    let not_found = || "Could not find `members = [...]` in the workspace manifest".to_string();
+    let start = manifest.find("members = [").ok_or_else(not_found)? + "members = [".len();
+    let end = start + manifest[start..].find(']').ok_or_else(not_found)?;
+
+    let mut members: Vec<_> = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect();
+    if members.contains(&name) {
+        return Err(format!("{name} is already a workspace member"));
+    }
+    members.push(name);
+    members.sort_by_key(|member| {
+        (
+            member.starts_with("day-"),
+            member.starts_with("day-").then_some(*member),
+        )
+    });
+
+    let members = members
+        .iter()
+        .map(|member| format!("\"{member}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Ok(format!(
+        "{}{members}{}",
+        &manifest[..start],
+        &manifest[end..]
+    ))
+}
+
+/// Inserts `line` among the lines that start with `prefix`, in sorted order
+/// and with the same indentation.
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<_> = text.lines().map(String::from).collect();
+    let matching: Vec<_> = (0..lines.len())
+        .filter(|&i| lines[i].trim_start().starts_with(prefix))
+        .collect();
+    let last = *matching
+        .last()
+        .ok_or(format!("Could not find where to add `{line}`"))?;
+    if matching.iter().any(|&i| lines[i].trim() == line) {
+        return Err(format!("`{line}` is already there"));
+    }
+
+    let indent = &lines[last][..lines[last].len() - lines[last].trim_start().len()];
+    let line = format!("{indent}{line}");
+    let index = matching
+        .iter()
+        .find(|&&i| lines[i].trim() > line.trim())
+        .copied()
+        .unwrap_or(last + 1);
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to the runner's `DAYS` array and bumps its length.
+fn register_day(days: &str, day: u8) -> Result<String, String> {
+    let days = insert_sorted(days, "&day_", &format!("&day_{day:02}::Day{day:02},"))?;
+    let (before, after) = days
+        .split_once("[&dyn Runnable; ")
+        .ok_or("Could not find the `DAYS` array".to_string())?;
+    let (count, rest) = after
+        .split_once(']')
+        .ok_or("Could not find the `DAYS` array".to_string())?;
+    let count: usize = count
+        .parse()
+        .map_err(|_| format!("Unexpected `DAYS` length `{count}`"))?;
+    Ok(format!("{before}[&dyn Runnable; {}]{rest}", count + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_the_day() {
+        let manifest = "[workspace]\nmembers = [\"aoc\", \"grid\", \"day-01\", \"day-03\"]\n";
+        assert_eq!(
+            add_member(manifest, "day-02").unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"grid\", \"day-01\", \"day-02\", \"day-03\"]\n"
+        );
+        assert!(add_member(manifest, "day-03").is_err());
+
+        let days = "pub static DAYS: [&dyn Runnable; 2] = [\n    &day_01::Day01,\n    &day_02::Day02,\n];\n";
+        assert_eq!(
+            register_day(days, 21).unwrap(),
+            "pub static DAYS: [&dyn Runnable; 3] = [\n    &day_01::Day01,\n    &day_02::Day02,\n    &day_21::Day21,\n];\n"
+        );
+    }
+
+    #[test]
+    fn creates_the_crate() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"day-01\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\nclap = \"4\"\nday-01 = { path = \"../day-01\" }\nserde = \"1\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "pub static DAYS: [&dyn Runnable; 1] = [\n    &day_01::Day01,\n];\n",
+        )
+        .unwrap();
+
+        create(root, 21).unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap(),
+            "[dependencies]\nclap = \"4\"\nday-01 = { path = \"../day-01\" }\nday-21 = { path = \"../day-21\" }\nserde = \"1\"\n"
+        );
+        let lib = fs::read_to_string(root.join("day-21/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day21;") && lib.contains("const DAY: u8 = 21;"));
+        assert!(root.join("day-21/benches/day_21.rs").exists());
+        assert!(create(root, 21).is_err());
+    }
+}
@@ -0,0 +1,291 @@
+use std::{fs, io, path::Path};
+
+use aoc_core::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+    /// Submitted too soon after the previous answer, so it was not checked.
+    Wait {
+        seconds: u64,
+    },
+}
+
+impl Outcome {
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+/// What the answer page said, and how long to wait before the next attempt.
+pub fn parse_response(body: &str) -> Result<(Outcome, u64), String> {
+    let text = body.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if text.contains("That's the right answer") {
+        Ok((Outcome::Correct, 0))
+    } else if text.contains("That's not the right answer") {
+        let outcome = if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        };
+        Ok((outcome, wrong_answer_delay(&text)))
+    } else if text.contains("You gave an answer too recently") {
+        let seconds = left_to_wait(&text)
+            .ok_or("Could not find how long to wait in the response".to_string())?;
+        Ok((Outcome::Wait { seconds }, seconds))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok((Outcome::AlreadySolved, 0))
+    } else {
+        Err("Could not understand the response to the submission".to_string())
+    }
+}
+
+/// "Please wait 5 minutes before trying again."
+fn wrong_answer_delay(text: &str) -> u64 {
+    let mut words = text
+        .split(' ')
+        .skip_while(|word| !word.eq_ignore_ascii_case("wait"));
+    let minutes = match words.nth(1) {
+        Some("one") => 1,
+        Some(count) => count.parse().unwrap_or(1),
+        None => 1,
+    };
+    minutes * 60
+}
+
+/// "You have 1m 30s left to wait."
+fn left_to_wait(text: &str) -> Option<u64> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, time) = before.rsplit_once("You have ")?;
+    time.split(' ').try_fold(0, |total, part| {
+        let seconds = match part.split_at_checked(part.len().checked_sub(1)?)? {
+            (minutes, "m") => minutes.parse::<u64>().ok()? * 60,
+            (seconds, "s") => seconds.parse::<u64>().ok()?,
+            _ => return None,
+        };
+        Some(total + seconds)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix time of the submission.
+    pub at: u64,
+}
+
+/// Every answer submitted so far, so that known answers are never sent twice.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Log {
+    pub submissions: Vec<Submission>,
+    /// Unix time before which the site will not accept another answer.
+    pub wait_until: u64,
+}
+
+impl Log {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text)
+                .map_err(|e| format!("Could not parse {}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Log::default()),
+            Err(e) => Err(format!("Could not read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, text).map_err(|e| format!("Could not write {}: {e}", path.display()))
+    }
+
+    /// Refuses answers that the log already knows the outcome of, or that
+    /// would be sent before the rate limit has passed.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<(), String> {
+        let part = u8::from(part);
+        let previous = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part);
+
+        for submission in previous {
+            let known = match submission.outcome {
+                Outcome::Correct if submission.answer == answer => "is already accepted",
+                Outcome::Correct => {
+                    return Err(format!(
+                        "Day {day} part {part} is already solved with {}",
+                        submission.answer
+                    ));
+                }
+                outcome if outcome.is_wrong() && submission.answer == answer => {
+                    "was already rejected"
+                }
+                Outcome::TooHigh if is_at_least(answer, &submission.answer) => {
+                    "is not below an answer that was too high"
+                }
+                Outcome::TooLow if is_at_least(&submission.answer, answer) => {
+                    "is not above an answer that was too low"
+                }
+                _ => continue,
+            };
+            return Err(format!("{answer} {known} for day {day} part {part}"));
+        }
+
+        if now < self.wait_until {
+            return Err(format!(
+                "Wait {}s before submitting another answer",
+                self.wait_until - now
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: Part,
+        answer: &str,
+        outcome: Outcome,
+        delay: u64,
+        now: u64,
+    ) {
+        self.wait_until = now + delay;
+        if !matches!(outcome, Outcome::Wait { .. }) {
+            self.submissions.push(Submission {
+                day,
+                part: part.into(),
+                answer: answer.to_string(),
+                outcome,
+                at: now,
+            });
+        }
+    }
+}
+
+fn is_at_least(a: &str, b: &str) -> bool {
+    match (a.parse::<i128>(), b.parse::<i128>()) {
+        (Ok(a), Ok(b)) => a >= b,
+        _ => false,
+    }
+}
+
+/// Submits an answer unless the log says it is pointless, and records what
+/// the site replied.
+pub fn submit(
+    client: &Client,
+    log: &mut Log,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Outcome, String> {
+    log.check(day, part, answer, now)?;
+    let body = client.submit(day, part, answer)?;
+    let (outcome, delay) = parse_response(&body)?;
+    log.record(day, part, answer, outcome, delay, now);
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    #[test]
+    fn parses_responses() {
+        let page =
+            |text: &str| format!("<html><main><article><p>{text}</p></article></main></html>");
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Ok((Outcome::Correct, 0))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high. \
+                 Please wait one minute before trying again."
+            )),
+            Ok((Outcome::TooHigh, 60))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer. Please wait 5 minutes before trying again."
+            )),
+            Ok((Outcome::Wrong, 300))
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again. You have 1m 30s left to wait."
+            )),
+            Ok((Outcome::Wait { seconds: 90 }, 90))
+        );
+        assert!(parse_response(&page("Something else")).is_err());
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let mut log = Log::default();
+        log.record(1, Part::One, "50", Outcome::TooHigh, 60, 1000);
+        log.record(1, Part::One, "10", Outcome::TooLow, 60, 2000);
+
+        assert!(log.check(1, Part::One, "50", 5000).is_err());
+        assert!(log.check(1, Part::One, "70", 5000).is_err());
+        assert!(log.check(1, Part::One, "5", 5000).is_err());
+        assert!(log.check(1, Part::One, "30", 2030).is_err());
+        assert_eq!(log.check(1, Part::One, "30", 5000), Ok(()));
+        assert_eq!(log.check(1, Part::Two, "50", 5000), Ok(()));
+    }
+
+    #[test]
+    fn submits_and_records() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        let handle = thread::spawn(move || {
+            let mut request = server.recv().unwrap();
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let url = request.url().to_string();
+            request
+                .respond(Response::from_string(
+                    "<p>That's not the right answer; your answer is too low.</p>",
+                ))
+                .unwrap();
+            (url, body)
+        });
+
+        let client = Client::new(&base_url, "secret");
+        let mut log = Log::default();
+        assert_eq!(
+            submit(&client, &mut log, 3, Part::Two, "48", 1000),
+            Ok(Outcome::TooLow)
+        );
+        assert_eq!(
+            handle.join().unwrap(),
+            (
+                "/2024/day/3/answer".to_string(),
+                "level=2&answer=48".to_string()
+            )
+        );
+        assert_eq!(log.wait_until, 1060);
+        assert_eq!(log.submissions[0].outcome, Outcome::TooLow);
+        assert!(submit(&client, &mut log, 3, Part::Two, "48", 5000).is_err());
+    }
+}
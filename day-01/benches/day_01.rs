@@ -0,0 +1,9 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_01::Day01;
+
+fn bench(c: &mut Criterion) {
+    aoc_core::criterion(c, &Day01);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
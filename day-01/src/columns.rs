@@ -0,0 +1,238 @@
+use std::{collections::HashMap, hash::Hash, str::FromStr};
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, Zero};
+use thiserror::Error;
+
+/// A number the lists can hold: any primitive integer, signed or not, or a
+/// [`BigInt`](num_bigint::BigInt) when nothing else is big enough.
+pub trait Value:
+    Clone + Ord + Hash + FromStr + Zero + CheckedAdd + CheckedSub + CheckedMul + FromPrimitive
+{
+}
+
+impl<T> Value for T where
+    T: Clone + Ord + Hash + FromStr + Zero + CheckedAdd + CheckedSub + CheckedMul + FromPrimitive
+{
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("the total does not fit in the value type")]
+pub struct Overflow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ColumnError {
+    #[error("column {index} has {found} values, expected {expected}")]
+    UnevenLength {
+        index: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("there is no column {0}")]
+    Missing(usize),
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+/// Any number of columns of location ids, read side by side from a list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns<T = u64> {
+    columns: Vec<Vec<T>>,
+}
+
+impl<T: Value> Columns<T> {
+    /// Fails unless every column is as long as the first.
+    pub fn new(columns: Vec<Vec<T>>) -> Result<Self, ColumnError> {
+        if let Some(first) = columns.first() {
+            let expected = first.len();
+            if let Some((index, column)) = columns
+                .iter()
+                .enumerate()
+                .find(|(_, column)| column.len() != expected)
+            {
+                return Err(ColumnError::UnevenLength {
+                    index,
+                    expected,
+                    found: column.len(),
+                });
+            }
+        }
+        Ok(Columns { columns })
+    }
+
+    /// How many columns there are.
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn column(&self, index: usize) -> Option<&[T]> {
+        self.columns.get(index).map(Vec::as_slice)
+    }
+
+    fn existing(&self, index: usize) -> Result<&[T], ColumnError> {
+        self.column(index).ok_or(ColumnError::Missing(index))
+    }
+
+    /// Total distance between two columns once both are sorted.
+    pub fn distance(&self, a: usize, b: usize) -> Result<T, ColumnError> {
+        distance(self.existing(a)?, self.existing(b)?)
+    }
+
+    /// Each value of column `a` times how often it appears in column `b`.
+    pub fn similarity(&self, a: usize, b: usize) -> Result<T, ColumnError> {
+        Ok(similarity(self.existing(a)?, self.existing(b)?)?)
+    }
+
+    /// How often each value appears in a column.
+    pub fn counts(&self, index: usize) -> Option<HashMap<T, usize>> {
+        self.column(index).map(counts)
+    }
+
+    /// The middle value of a column, the lower one of the two middle values
+    /// when the column has an even length.
+    pub fn median(&self, index: usize) -> Option<T> {
+        let mut values = self.column(index)?.to_vec();
+        let middle = values.len().checked_sub(1)? / 2;
+        Some(values.select_nth_unstable(middle).1.clone())
+    }
+
+    pub fn min(&self, index: usize) -> Option<T> {
+        self.column(index)?.iter().min().cloned()
+    }
+
+    pub fn max(&self, index: usize) -> Option<T> {
+        self.column(index)?.iter().max().cloned()
+    }
+}
+
+impl<T> Default for Columns<T> {
+    fn default() -> Self {
+        Columns { columns: vec![] }
+    }
+}
+
+impl<T: Value> TryFrom<(Vec<T>, Vec<T>)> for Columns<T> {
+    type Error = ColumnError;
+
+    fn try_from((left, right): (Vec<T>, Vec<T>)) -> Result<Self, ColumnError> {
+        Columns::new(vec![left, right])
+    }
+}
+
+fn checked_sum<T: Value>(values: impl IntoIterator<Item = Option<T>>) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::zero(), |total, value| {
+        value
+            .and_then(|value| total.checked_add(&value))
+            .ok_or(Overflow)
+    })
+}
+
+/// Fails when the columns have different lengths rather than leave values
+/// out.
+pub fn distance<T: Value>(left: &[T], right: &[T]) -> Result<T, ColumnError> {
+    if left.len() != right.len() {
+        return Err(ColumnError::UnevenLength {
+            index: 1,
+            expected: left.len(),
+            found: right.len(),
+        });
+    }
+    let (mut left, mut right) = (left.to_vec(), right.to_vec());
+    left.sort_unstable();
+    right.sort_unstable();
+    Ok(checked_sum(left.iter().zip(&right).map(
+        |(left, right)| match left >= right {
+            true => left.checked_sub(right),
+            false => right.checked_sub(left),
+        },
+    ))?)
+}
+
+pub fn similarity<T: Value>(left: &[T], right: &[T]) -> Result<T, Overflow> {
+    let counts = counts(right);
+    checked_sum(left.iter().map(|value| {
+        let count = T::from_usize(*counts.get(value).unwrap_or(&0))?;
+        value.checked_mul(&count)
+    }))
+}
+
+fn counts<T: Value>(values: &[T]) -> HashMap<T, usize> {
+    let mut counts = HashMap::new();
+    for value in values {
+        *counts.entry(value.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use super::*;
+
+    #[test]
+    fn compares_any_two_columns() {
+        let columns = Columns::new(vec![vec![3, 4, 2], vec![4, 3, 5], vec![3, 3, 9]]).unwrap();
+        assert_eq!(columns.width(), 3);
+        assert_eq!(columns.distance(0, 1), Ok(3));
+        assert_eq!(columns.distance(1, 2), Ok(5));
+        assert_eq!(columns.similarity(0, 2), Ok(6));
+        assert_eq!(columns.similarity(2, 0), Ok(6));
+        assert_eq!(columns.counts(2), Some(HashMap::from([(3, 2), (9, 1)])));
+        assert_eq!(columns.distance(0, 3), Err(ColumnError::Missing(3)));
+        assert_eq!(columns.column(3), None);
+    }
+
+    #[test]
+    fn rejects_uneven_columns() {
+        assert_eq!(
+            Columns::new(vec![vec![1, 2], vec![3, 4], vec![5]]),
+            Err(ColumnError::UnevenLength {
+                index: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            distance(&[1, 2], &[3]),
+            Err(ColumnError::UnevenLength {
+                index: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert!(Columns::try_from((vec![1], vec![])).is_err());
+    }
+
+    #[test]
+    fn summarizes_a_column() {
+        let columns = Columns::new(vec![vec![5, 1, 4, 2], vec![6, 6, 6, 6]]).unwrap();
+        assert_eq!(columns.median(0), Some(2));
+        assert_eq!(columns.min(0), Some(1));
+        assert_eq!(columns.max(0), Some(5));
+        assert_eq!(columns.median(2), None);
+        assert_eq!(columns.max(2), None);
+
+        let empty = Columns::<u64>::new(vec![vec![], vec![]]).unwrap();
+        assert_eq!(empty.median(0), None);
+        assert_eq!(empty.max(1), None);
+    }
+
+    #[test]
+    fn checks_for_overflow() {
+        assert_eq!(distance(&[-3i64, 2], &[4, -1]), Ok(4));
+        assert_eq!(distance(&[i64::MIN], &[1]), Err(Overflow.into()));
+        assert_eq!(
+            similarity(&[u64::MAX], &[u64::MAX, u64::MAX]),
+            Err(Overflow)
+        );
+
+        let big = |value: &str| value.parse::<BigInt>().unwrap();
+        assert_eq!(
+            similarity(
+                &[big("18446744073709551615")],
+                &vec![big("18446744073709551615"); 2]
+            ),
+            Ok(big("36893488147419103230"))
+        );
+    }
+}
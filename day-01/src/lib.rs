@@ -0,0 +1,242 @@
+mod columns;
+
+use std::io::BufRead;
+
+use aoc_core::{Answer, Error, Position, Solution};
+use parsing::{Line, ReadError};
+use thiserror::Error;
+
+pub use columns::{distance, similarity, ColumnError, Columns, Overflow, Value};
+pub use num_bigint::BigInt;
+
+/// The left and right location id columns.
+pub type VecPair<T = u64> = (Vec<T>, Vec<T>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("invalid number `{text}` at {position}")]
+    InvalidNumber { position: Position, text: String },
+    #[error("line {line} has {found} values, expected {expected}")]
+    WrongColumnCount {
+        line: usize,
+        found: usize,
+        expected: usize,
+    },
+    #[error(transparent)]
+    Columns(#[from] ColumnError),
+}
+
+/// Reads big integers, so that any list can be solved, negative ids included.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = VecPair<BigInt>;
+
+    type Error = ParseError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_2(input)?.to_string())
+    }
+}
+
+fn parse_value<T: Value>(line: Line, value: &str) -> Result<T, ParseError> {
+    line.parse(value, |position, text| ParseError::InvalidNumber {
+        position,
+        text,
+    })
+}
+
+fn parse_line<T: Value>(line: Line) -> Result<(T, T), ParseError> {
+    let values = line.text.split_whitespace().collect::<Vec<_>>();
+    let [left, right] = values[..] else {
+        return Err(ParseError::WrongColumnCount {
+            line: line.number(),
+            found: values.len(),
+            expected: 2,
+        });
+    };
+
+    Ok((parse_value(line, left)?, parse_value(line, right)?))
+}
+
+pub fn parse_input<T: Value>(input: &str) -> Result<VecPair<T>, ParseError> {
+    parsing::lines(input).map(parse_line).collect()
+}
+
+/// Any number of columns, with as many values on every line as on the first.
+pub fn parse_columns<T: Value>(input: &str) -> Result<Columns<T>, ParseError> {
+    let mut columns: Vec<Vec<T>> = vec![];
+    for line in parsing::lines(input) {
+        let values = line.text.split_whitespace().collect::<Vec<_>>();
+        if columns.is_empty() {
+            columns = vec![vec![]; values.len()];
+        }
+        if values.len() != columns.len() {
+            return Err(ParseError::WrongColumnCount {
+                line: line.number(),
+                found: values.len(),
+                expected: columns.len(),
+            });
+        }
+        for (column, value) in columns.iter_mut().zip(values) {
+            column.push(parse_value(line, value)?);
+        }
+    }
+    Ok(Columns::new(columns)?)
+}
+
+/// Like [`parse_input`], reading one line at a time so that only the columns
+/// are kept in memory and not the text.
+pub fn read_input<T: Value>(reader: impl BufRead) -> Result<VecPair<T>, ReadError<ParseError>> {
+    let (mut left_col, mut right_col) = (vec![], vec![]);
+    parsing::read_lines(reader, |line| {
+        let (left, right) = parse_line(line)?;
+        left_col.push(left);
+        right_col.push(right);
+        Ok(())
+    })?;
+    Ok((left_col, right_col))
+}
+
+/// Total distance between the columns once both are sorted.
+pub fn part_1<T: Value>((left_col, right_col): &VecPair<T>) -> Result<T, ColumnError> {
+    distance(left_col, right_col)
+}
+
+/// Similarity score: each left value times how often it appears on the right.
+pub fn part_2<T: Value>((left_col, right_col): &VecPair<T>) -> Result<T, Overflow> {
+    similarity(left_col, right_col)
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Params, Part, Runnable};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Pairs the sorted columns, in a type wide enough for any `i64` input.
+    fn slow_distance(left: &[i64], right: &[i64]) -> i128 {
+        let (mut left, mut right) = (left.to_vec(), right.to_vec());
+        left.sort();
+        right.sort();
+        left.iter()
+            .zip(&right)
+            .map(|(l, r)| (*l as i128 - *r as i128).abs())
+            .sum()
+    }
+
+    /// Counts every left value by scanning the whole right column.
+    fn slow_similarity(left: &[i64], right: &[i64]) -> i128 {
+        left.iter()
+            .map(|value| *value as i128 * right.iter().filter(|v| *v == value).count() as i128)
+            .sum()
+    }
+
+    /// Mostly the values at the ends of `i64`, where the sums overflow it.
+    fn extreme() -> impl Strategy<Value = i64> {
+        prop_oneof![
+            3 => prop::sample::select(vec![i64::MIN, i64::MIN + 1, -1, 0, i64::MAX]),
+            1 => any::<i64>(),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn matches_slow_versions(
+            rows in prop::collection::vec((-50..50i64, -50..50i64), 0..100),
+        ) {
+            let (left, right): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
+            let expected = (slow_distance(&left, &right), slow_similarity(&left, &right));
+            let pair = (left, right);
+            prop_assert_eq!(part_1(&pair), Ok(expected.0 as i64));
+            prop_assert_eq!(part_2(&pair), Ok(expected.1 as i64));
+        }
+
+        #[test]
+        fn big_integers_do_not_overflow(
+            rows in prop::collection::vec((extreme(), extreme()), 0..20),
+        ) {
+            let (left, right): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
+            let big = |column: &[i64]| column.iter().map(|v| BigInt::from(*v)).collect::<Vec<_>>();
+            let pair = (big(&left), big(&right));
+            prop_assert_eq!(part_1(&pair), Ok(BigInt::from(slow_distance(&left, &right))));
+            prop_assert_eq!(part_2(&pair), Ok(BigInt::from(slow_similarity(&left, &right))));
+        }
+    }
+
+    #[test]
+    fn solves_any_integers() {
+        let input = format!("-3   {max}\n{max}   -3\n", max = u64::MAX);
+        let results = Day01.run(&input, &Part::ALL, Params::default()).unwrap();
+        let answers: Vec<_> = results.iter().map(|r| r.answer.as_str()).collect();
+        assert_eq!(answers, ["0", "18446744073709551612"]);
+
+        let overflow = part_2(&(vec![u64::MAX], vec![u64::MAX, u64::MAX]));
+        assert_eq!(overflow, Err(Overflow));
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input::<u64>("3   4\n4   x3\n"),
+            Err(ParseError::InvalidNumber {
+                position: Position { line: 2, column: 5 },
+                text: "x3".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input::<u64>("3   4\n4\n"),
+            Err(ParseError::WrongColumnCount {
+                line: 2,
+                found: 1,
+                expected: 2
+            })
+        );
+    }
+
+    #[test]
+    fn parses_wide_lists() {
+        let columns = parse_columns::<u64>("3 4 1\n4 3 1\n").unwrap();
+        assert_eq!(columns.width(), 3);
+        assert_eq!(columns.column(2), Some(&[1, 1][..]));
+        assert_eq!(
+            parse_columns::<u64>("3 4 1\n4 3\n"),
+            Err(ParseError::WrongColumnCount {
+                line: 2,
+                found: 2,
+                expected: 3
+            })
+        );
+    }
+
+    #[test]
+    fn reads_like_parse() {
+        let input = "3   4\n-4   3\n\n2   5\n";
+        assert_eq!(
+            read_input::<i64>(input.as_bytes()).unwrap(),
+            parse_input(input).unwrap()
+        );
+        assert!(matches!(
+            read_input::<u64>("3   4\n4\n".as_bytes()),
+            Err(ReadError::Parse(ParseError::WrongColumnCount {
+                line: 2,
+                ..
+            }))
+        ));
+    }
+}
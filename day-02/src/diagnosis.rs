@@ -0,0 +1,235 @@
+use std::fmt::{self, Write};
+
+use crate::{SafetyPolicy, Trend};
+
+/// Why two neighbouring levels break a policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// The levels go against a fixed trend.
+    WrongDirection,
+    /// The levels go the other way from the ones before them.
+    DirectionFlip,
+    ZeroStep,
+    StepTooSmall,
+    StepTooBig,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Reason::WrongDirection => "wrong direction",
+            Reason::DirectionFlip => "direction flip",
+            Reason::ZeroStep => "zero step",
+            Reason::StepTooSmall => "step too small",
+            Reason::StepTooBig => "step too big",
+        })
+    }
+}
+
+/// The first pair of levels in a report that breaks a policy, starting at
+/// `index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub index: usize,
+    pub values: (usize, usize),
+    pub reason: Reason,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (first, second) = self.values;
+        write!(
+            f,
+            "levels {}-{} ({first}, {second}): {}",
+            self.index + 1,
+            self.index + 2,
+            self.reason
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    /// Safe once the levels at these indices are removed.
+    Dampened {
+        removed: Vec<usize>,
+    },
+    Unsafe,
+}
+
+/// What a policy makes of one report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// The first violation with every level kept, which is `None` exactly
+    /// when the verdict is [`Verdict::Safe`].
+    pub violation: Option<Violation>,
+    pub verdict: Verdict,
+}
+
+impl fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.verdict {
+            Verdict::Safe => f.write_str("safe")?,
+            Verdict::Dampened { removed } => {
+                let levels = removed
+                    .iter()
+                    .map(|index| (index + 1).to_string())
+                    .collect::<Vec<_>>();
+                match levels.len() {
+                    1 => write!(f, "safe without level {}", levels[0])?,
+                    _ => write!(f, "safe without levels {}", levels.join(", "))?,
+                }
+            }
+            Verdict::Unsafe => f.write_str("unsafe")?,
+        }
+        match &self.violation {
+            Some(violation) => write!(f, "; {violation}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl SafetyPolicy {
+    /// The first pair of levels that breaks the policy when no level is
+    /// removed. Without a fixed trend the first pair that moves sets it.
+    pub fn first_violation(&self, report: &[usize]) -> Option<Violation> {
+        let mut increasing = match self.trend {
+            Trend::Increasing => Some(true),
+            Trend::Decreasing => Some(false),
+            Trend::Either => None,
+        };
+        report.windows(2).enumerate().find_map(|(index, pair)| {
+            let (first, second) = (pair[0], pair[1]);
+            let step = first.abs_diff(second);
+            let rising = second > first;
+            let reason = if step == 0 {
+                (self.min_step > 0).then_some(Reason::ZeroStep)
+            } else if increasing.is_some_and(|increasing| increasing != rising) {
+                Some(match self.trend {
+                    Trend::Either => Reason::DirectionFlip,
+                    _ => Reason::WrongDirection,
+                })
+            } else {
+                increasing = Some(rising);
+                if step < self.min_step {
+                    Some(Reason::StepTooSmall)
+                } else if step > self.max_step {
+                    Some(Reason::StepTooBig)
+                } else {
+                    None
+                }
+            };
+            reason.map(|reason| Violation {
+                index,
+                values: (first, second),
+                reason,
+            })
+        })
+    }
+
+    pub fn diagnose(&self, report: &[usize]) -> Diagnosis {
+        let violation = self.first_violation(report);
+        let verdict = match (violation, self.removed_levels(report)) {
+            (None, _) => Verdict::Safe,
+            (Some(_), Some(removed)) => Verdict::Dampened { removed },
+            (Some(_), None) => Verdict::Unsafe,
+        };
+        Diagnosis { violation, verdict }
+    }
+}
+
+/// One line per report: its levels, then what the policy makes of them.
+pub fn annotate(reports: &[Vec<usize>], policy: &SafetyPolicy) -> String {
+    let mut annotated = String::new();
+    for report in reports {
+        let levels = report
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let _ = writeln!(annotated, "{levels}: {}", policy.diagnose(report));
+    }
+    annotated
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn diagnoses_the_example() {
+        let damped = SafetyPolicy {
+            tolerance: 1,
+            ..SafetyPolicy::default()
+        };
+        let violation = |index, values, reason| {
+            Some(Violation {
+                index,
+                values,
+                reason,
+            })
+        };
+
+        let diagnosis = damped.diagnose(&[1, 2, 7, 8, 9]);
+        assert_eq!(diagnosis.verdict, Verdict::Unsafe);
+        assert_eq!(
+            diagnosis.violation,
+            violation(1, (2, 7), Reason::StepTooBig)
+        );
+
+        let diagnosis = damped.diagnose(&[1, 3, 2, 4, 5]);
+        assert_eq!(diagnosis.verdict, Verdict::Dampened { removed: vec![1] });
+        assert_eq!(
+            diagnosis.violation,
+            violation(1, (3, 2), Reason::DirectionFlip)
+        );
+
+        let diagnosis = damped.diagnose(&[8, 6, 4, 4, 1]);
+        assert_eq!(diagnosis.verdict, Verdict::Dampened { removed: vec![2] });
+        assert_eq!(diagnosis.violation, violation(2, (4, 4), Reason::ZeroStep));
+
+        let rising = SafetyPolicy {
+            trend: Trend::Increasing,
+            ..SafetyPolicy::default()
+        };
+        assert_eq!(
+            rising.first_violation(&[7, 6, 4]),
+            violation(0, (7, 6), Reason::WrongDirection)
+        );
+    }
+
+    #[test]
+    fn annotates_reports() {
+        let reports = vec![
+            vec![7, 6, 4, 2, 1],
+            vec![1, 2, 7, 8, 9],
+            vec![1, 3, 2, 4, 5],
+        ];
+        let damped = SafetyPolicy {
+            tolerance: 1,
+            ..SafetyPolicy::default()
+        };
+        assert_eq!(
+            annotate(&reports, &damped),
+            "7 6 4 2 1: safe\n\
+             1 2 7 8 9: unsafe; levels 2-3 (2, 7): step too big\n\
+             1 3 2 4 5: safe without level 2; levels 2-3 (3, 2): direction flip\n"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn violation_means_not_safe(
+            report in prop::collection::vec(0..12usize, 1..8),
+            min_step in 0..2usize,
+            max_step in 1..4usize,
+            trend in prop::sample::select(vec![Trend::Increasing, Trend::Decreasing, Trend::Either]),
+        ) {
+            let policy = SafetyPolicy { min_step, max_step, trend, tolerance: 0 };
+            prop_assert_eq!(policy.first_violation(&report).is_none(), policy.is_safe(&report));
+        }
+    }
+}
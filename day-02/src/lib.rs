@@ -0,0 +1,187 @@
+mod diagnosis;
+mod policy;
+
+use std::io::BufRead;
+
+use aoc_core::{parse_param, unknown_param, Answer, Error, Position, Solution};
+use parsing::{Line, ReadError};
+use thiserror::Error;
+
+pub use diagnosis::{annotate, Diagnosis, Reason, Verdict, Violation};
+pub use policy::{SafetyPolicy, Trend};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("invalid level `{text}` at {position}")]
+    InvalidLevel { position: Position, text: String },
+    #[error("report on line {line} has fewer than two levels")]
+    TooFewLevels { line: usize },
+}
+
+/// The safety rules shared by both parts, and how many bad levels each part
+/// tolerates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub min_step: usize,
+    pub max_step: usize,
+    pub trend: Trend,
+    pub part_1_tolerance: usize,
+    pub part_2_tolerance: usize,
+}
+
+impl Config {
+    pub fn part_1(&self) -> SafetyPolicy {
+        self.policy(self.part_1_tolerance)
+    }
+
+    pub fn part_2(&self) -> SafetyPolicy {
+        self.policy(self.part_2_tolerance)
+    }
+
+    fn policy(&self, tolerance: usize) -> SafetyPolicy {
+        SafetyPolicy {
+            min_step: self.min_step,
+            max_step: self.max_step,
+            trend: self.trend,
+            tolerance,
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let policy = SafetyPolicy::default();
+        Config {
+            min_step: policy.min_step,
+            max_step: policy.max_step,
+            trend: policy.trend,
+            part_1_tolerance: 0,
+            part_2_tolerance: 1,
+        }
+    }
+}
+
+impl aoc_core::Config for Config {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "min_step" => self.min_step = parse_param(key, value)?,
+            "max_step" => self.max_step = parse_param(key, value)?,
+            "trend" => self.trend = parse_param(key, value)?,
+            "part_1_tolerance" => self.part_1_tolerance = parse_param(key, value)?,
+            "part_2_tolerance" => self.part_2_tolerance = parse_param(key, value)?,
+            _ => return Err(unknown_param(key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = (Vec<Vec<usize>>, Config);
+
+    type Error = ParseError;
+
+    type Config = Config;
+
+    fn parse(&self, input: &str, config: &Config) -> Result<Self::Input, Self::Error> {
+        Ok((parse_input(input)?, *config))
+    }
+
+    fn part_1(&self, (reports, config): &Self::Input) -> Result<Answer, Error> {
+        Ok(count_safe_reports(reports, &config.part_1()).to_string())
+    }
+
+    fn part_2(&self, (reports, config): &Self::Input) -> Result<Answer, Error> {
+        Ok(count_safe_reports(reports, &config.part_2()).to_string())
+    }
+}
+
+fn parse_report(line: Line) -> Result<Vec<usize>, ParseError> {
+    let levels = line
+        .text
+        .split_whitespace()
+        .map(|value| {
+            line.parse(value, |position, text| ParseError::InvalidLevel {
+                position,
+                text,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match levels.len() {
+        0 | 1 => Err(ParseError::TooFewLevels {
+            line: line.number(),
+        }),
+        _ => Ok(levels),
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    parsing::lines(input).map(parse_report).collect()
+}
+
+/// The answers to both parts, reading and checking one report at a time.
+pub fn count_safe(
+    reader: impl BufRead,
+    config: &Config,
+) -> Result<(usize, usize), ReadError<ParseError>> {
+    let (part_1, part_2) = (config.part_1(), config.part_2());
+    let mut counts = (0, 0);
+    parsing::read_lines(reader, |line| {
+        let report = parse_report(line)?;
+        counts.0 += part_1.is_safe(&report) as usize;
+        counts.1 += part_2.is_safe(&report) as usize;
+        Ok(())
+    })?;
+    Ok(counts)
+}
+
+pub fn count_safe_reports(reports: &[Vec<usize>], policy: &SafetyPolicy) -> usize {
+    reports
+        .iter()
+        .filter(|report| policy.is_safe(report))
+        .count()
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("7 6 4 2 1\n1 2 7 -8 9\n"),
+            Err(ParseError::InvalidLevel {
+                position: Position { line: 2, column: 7 },
+                text: "-8".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input("7 6 4 2 1\n\n3\n"),
+            Err(ParseError::TooFewLevels { line: 3 })
+        );
+    }
+
+    #[test]
+    fn counts_while_reading() {
+        let input = "7 6 4 2 1\n1 2 7 8 9\n1 3 2 4 5\n";
+        let reports = parse_input(input).unwrap();
+        let config = Config::default();
+        assert_eq!(
+            count_safe(input.as_bytes(), &config).unwrap(),
+            (
+                count_safe_reports(&reports, &config.part_1()),
+                count_safe_reports(&reports, &config.part_2())
+            )
+        );
+        assert!(matches!(
+            count_safe("7 6 4 2 1\n3\n".as_bytes(), &config),
+            Err(ReadError::Parse(ParseError::TooFewLevels { line: 2 }))
+        ));
+    }
+}
@@ -0,0 +1,186 @@
+use std::str::FromStr;
+
+/// Which way the levels of a safe report have to go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as the whole report agrees.
+    Either,
+}
+
+impl FromStr for Trend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "increasing" => Ok(Trend::Increasing),
+            "decreasing" => Ok(Trend::Decreasing),
+            "either" => Ok(Trend::Either),
+            _ => Err(format!("unknown trend `{s}`")),
+        }
+    }
+}
+
+/// When a report counts as safe. The default is the rule from part 1: levels
+/// all increase or all decrease, by 1 to 3 at a time, with no bad levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    pub min_step: usize,
+    pub max_step: usize,
+    pub trend: Trend,
+    /// How many levels may be removed to make a report safe.
+    pub tolerance: usize,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        SafetyPolicy {
+            min_step: 1,
+            max_step: 3,
+            trend: Trend::Either,
+            tolerance: 0,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    pub fn is_safe(&self, report: &[usize]) -> bool {
+        self.removals(report).is_some()
+    }
+
+    /// How many levels [`removed_levels`](Self::removed_levels) removes.
+    pub fn removals(&self, report: &[usize]) -> Option<usize> {
+        self.removed_levels(report).map(|removed| removed.len())
+    }
+
+    /// The indices of the fewest levels to remove for the rest of the report
+    /// to follow the policy, or `None` when that takes more than the tolerance.
+    pub fn removed_levels(&self, report: &[usize]) -> Option<Vec<usize>> {
+        match self.trend {
+            Trend::Either => {
+                let increasing = self.removed_along(report, true);
+                let decreasing = self.removed_along(report, false);
+                increasing
+                    .into_iter()
+                    .chain(decreasing)
+                    .min_by_key(Vec::len)
+            }
+            trend => self.removed_along(report, trend == Trend::Increasing),
+        }
+    }
+
+    pub(crate) fn fits(&self, first: usize, second: usize, increasing: bool) -> bool {
+        let step = match increasing {
+            true => second.checked_sub(first),
+            false => first.checked_sub(second),
+        };
+        step.is_some_and(|step| (self.min_step..=self.max_step).contains(&step))
+    }
+
+    /// Keeping a level means the previous kept level is at most `tolerance + 1`
+    /// levels back, so each level only looks at that many predecessors.
+    fn removed_along(&self, report: &[usize], increasing: bool) -> Option<Vec<usize>> {
+        let tolerance = self.tolerance;
+        // Fewest removals before each level when that level is kept, and the
+        // kept level before it, the latest one on ties.
+        let mut kept: Vec<Option<(usize, Option<usize>)>> = Vec::with_capacity(report.len());
+        for (i, level) in report.iter().enumerate() {
+            let from_previous = (i.saturating_sub(tolerance + 1)..i).rev().filter_map(|j| {
+                let removed = kept[j]?.0 + (i - j - 1);
+                (removed <= tolerance && self.fits(report[j], *level, increasing))
+                    .then_some((removed, Some(j)))
+            });
+            let first = (i <= tolerance).then_some((i, None));
+            kept.push(
+                from_previous
+                    .chain(first)
+                    .min_by_key(|(removed, _)| *removed),
+            );
+        }
+
+        let (last, _) = kept
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| Some((i, (*entry)?.0 + report.len() - 1 - i)))
+            .filter(|(_, removed)| *removed <= tolerance)
+            .min_by_key(|(_, removed)| *removed)?;
+
+        let mut removed = vec![true; report.len()];
+        let mut current = Some(last);
+        while let Some(i) = current {
+            removed[i] = false;
+            current = kept[i].and_then(|(_, previous)| previous);
+        }
+        Some((0..report.len()).filter(|i| removed[*i]).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Whether the report follows the policy after removing at most
+    /// `tolerance` levels, trying every way to remove them.
+    fn is_safe_brute_force(policy: &SafetyPolicy, report: &[usize], tolerance: usize) -> bool {
+        let follows = |increasing| {
+            report
+                .windows(2)
+                .all(|pair| policy.fits(pair[0], pair[1], increasing))
+        };
+        let trend_fits = match policy.trend {
+            Trend::Increasing => follows(true),
+            Trend::Decreasing => follows(false),
+            Trend::Either => follows(true) || follows(false),
+        };
+        trend_fits
+            || tolerance > 0
+                && (0..report.len()).any(|i| {
+                    let mut rest = report.to_vec();
+                    rest.remove(i);
+                    !rest.is_empty() && is_safe_brute_force(policy, &rest, tolerance - 1)
+                })
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(
+            report in prop::collection::vec(0..12usize, 1..8),
+            min_step in 0..2usize,
+            max_step in 1..4usize,
+            trend in prop::sample::select(vec![Trend::Increasing, Trend::Decreasing, Trend::Either]),
+            tolerance in 0..3usize,
+        ) {
+            let policy = SafetyPolicy { min_step, max_step, trend, tolerance };
+            prop_assert_eq!(
+                policy.is_safe(&report),
+                is_safe_brute_force(&policy, &report, tolerance)
+            );
+        }
+    }
+
+    #[test]
+    fn counts_removals() {
+        let damped = SafetyPolicy {
+            tolerance: 1,
+            ..SafetyPolicy::default()
+        };
+        assert_eq!(damped.removals(&[7, 6, 4, 2, 1]), Some(0));
+        assert_eq!(damped.removals(&[1, 3, 2, 4, 5]), Some(1));
+        assert_eq!(damped.removals(&[1, 2, 7, 8, 9]), None);
+
+        let rising = SafetyPolicy {
+            min_step: 2,
+            max_step: 5,
+            trend: Trend::Increasing,
+            tolerance: 2,
+        };
+        assert_eq!(
+            rising.removed_levels(&[1, 9, 3, 2, 8, 10]),
+            Some(vec![1, 3])
+        );
+        assert_eq!(rising.removals(&[10, 8, 6, 4]), None);
+    }
+}
@@ -0,0 +1,9 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_03::Day03;
+
+fn bench(c: &mut Criterion) {
+    aoc_core::criterion(c, &Day03);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,144 @@
+use aoc_core::{Answer, Error, Position, Solution};
+use parsing::Line;
+use regex::Regex;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ParseError {
+    #[error("invalid instruction pattern: {0}")]
+    Pattern(#[from] regex::Error),
+    #[error("factor `{text}` at {position} does not fit in 64 bits")]
+    InvalidFactor { position: Position, text: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("the sum of the products does not fit in 64 bits")]
+pub struct Overflow;
+
+/// The instructions that survived the corruption, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Instruction>;
+
+    type Error = ParseError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_2(input)?.to_string())
+    }
+}
+
+fn parse_line(line: Line, pattern: &Regex) -> Result<Vec<Instruction>, ParseError> {
+    let factor = |text| {
+        line.parse(text, |position, text| ParseError::InvalidFactor {
+            position,
+            text,
+        })
+    };
+    pattern
+        .captures_iter(line.text)
+        .map(|cap| match (cap.get(1), cap.get(2)) {
+            (Some(x), Some(y)) => Ok(Instruction::Mul(factor(x.as_str())?, factor(y.as_str())?)),
+            _ if &cap[0] == "do()" => Ok(Instruction::Do),
+            _ => Ok(Instruction::Dont),
+        })
+        .collect()
+}
+
+/// Every well-formed `mul(X,Y)`, `do()` and `don't()` instruction.
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let pattern = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)")?;
+    let mut instructions = vec![];
+    for line in parsing::lines(input) {
+        instructions.extend(parse_line(line, &pattern)?);
+    }
+    Ok(instructions)
+}
+
+fn sum_products(mut products: impl Iterator<Item = (u64, u64)>) -> Result<u64, Overflow> {
+    products.try_fold(0u64, |total, (x, y)| {
+        x.checked_mul(y)
+            .and_then(|product| total.checked_add(product))
+            .ok_or(Overflow)
+    })
+}
+
+/// Sum of every multiplication.
+pub fn part_1(instructions: &[Instruction]) -> Result<u64, Overflow> {
+    sum_products(
+        instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Instruction::Mul(x, y) => Some((*x, *y)),
+                Instruction::Do | Instruction::Dont => None,
+            }),
+    )
+}
+
+/// Like part 1, but `don't()` disables multiplications until the next `do()`.
+pub fn part_2(instructions: &[Instruction]) -> Result<u64, Overflow> {
+    let mut enabled = true;
+    sum_products(
+        instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Instruction::Mul(x, y) => enabled.then_some((*x, *y)),
+                Instruction::Do => {
+                    enabled = true;
+                    None
+                }
+                Instruction::Dont => {
+                    enabled = false;
+                    None
+                }
+            }),
+    )
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_overflow() {
+        let part_1 = |input| part_1(&parse_input(input).unwrap());
+        assert_eq!(part_1("mul(2,3)mul(4,5)"), Ok(26));
+        assert_eq!(part_1("mul(4294967296,4294967296)"), Err(Overflow));
+        let max = format!("mul({},1)mul(1,1)", u64::MAX);
+        assert_eq!(part_2(&parse_input(&max).unwrap()), Err(Overflow));
+    }
+
+    #[test]
+    fn parses_instructions_across_lines() {
+        let instructions = parse_input("xmul(2,4)don't()\nmul(5,5)do()?mul(8,5)\n").unwrap();
+        assert_eq!(part_2(&instructions), Ok(48));
+        assert_eq!(
+            parse_input("mul(1,1)\nmul(99999999999999999999,1)\n"),
+            Err(ParseError::InvalidFactor {
+                position: Position { line: 2, column: 5 },
+                text: "99999999999999999999".to_string()
+            })
+        );
+    }
+}
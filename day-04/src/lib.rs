@@ -0,0 +1,82 @@
+use aoc_core::{Answer, Error, Solution};
+use grid::{Grid, GridError, Point, NEIGHBORHOOD_8};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    type Error = GridError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        Grid::from_chars(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_1(input).to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_2(input).to_string())
+    }
+}
+
+/// Number of `XMAS` words starting at `point`, in any direction.
+pub fn count_xmas(grid: &Grid<char>, point: Point) -> usize {
+    if grid[point] != 'X' {
+        return 0;
+    }
+
+    let is_xmas = |(dx, dy): (isize, isize)| {
+        (1..4)
+            .map(|i| point.offset(dx * i, dy * i).and_then(|p| grid.get(p)))
+            .eq(['M', 'A', 'S'].iter().map(Some))
+    };
+
+    NEIGHBORHOOD_8.into_iter().filter(|d| is_xmas(*d)).count()
+}
+
+/// Whether `point` is the centre of two crossing `MAS` words.
+pub fn is_x_mas(grid: &Grid<char>, point: Point) -> bool {
+    let corner = |dx, dy| point.offset(dx, dy).and_then(|p| grid.get(p));
+    let corners = [corner(-1, -1), corner(1, 1), corner(1, -1), corner(-1, 1)];
+
+    grid[point] == 'A'
+        && corners
+            .iter()
+            .all(|c| c.is_some_and(|c| ['M', 'S'].contains(c)))
+        && corners[0] != corners[1]
+        && corners[2] != corners[3]
+}
+
+pub fn part_1(grid: &Grid<char>) -> usize {
+    grid.points().map(|point| count_xmas(grid, point)).sum()
+}
+
+pub fn part_2(grid: &Grid<char>) -> usize {
+    grid.points().filter(|point| is_x_mas(grid, *point)).count()
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            Grid::from_chars("XMAS\nXMA\n"),
+            Err(GridError::RaggedRow {
+                line: 2,
+                expected: 4,
+                found: 3
+            })
+        );
+        assert_eq!(Grid::from_chars("\n"), Err(GridError::Empty));
+    }
+}
@@ -0,0 +1,9 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_05::Day05;
+
+fn bench(c: &mut Criterion) {
+    aoc_core::criterion(c, &Day05);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
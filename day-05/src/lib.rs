@@ -0,0 +1,246 @@
+use aoc_core::{Answer, Error, Position, Solution};
+use itertools::*;
+use parsing::Line;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("invalid ordering rule `{text}` at {position}, expected `X|Y`")]
+    InvalidRule { position: Position, text: String },
+    #[error("invalid page number `{text}` at {position}")]
+    InvalidNumber { position: Position, text: String },
+    #[error("no updates after the ordering rules")]
+    MissingUpdates,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("the ordering rules between pages {pages:?} form a cycle")]
+pub struct CyclicRules {
+    pub pages: Vec<usize>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (RuleSet, Vec<Vec<usize>>);
+
+    type Error = ParseError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        let (rules, updates) = input;
+        Ok(part_1(rules, updates).to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        let (rules, updates) = input;
+        Ok(part_2(rules, updates)?.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Rule {
+    After,
+    Before,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct OrderRule(usize, usize);
+
+fn parse_number(line: Line, value: &str) -> Result<usize, ParseError> {
+    line.parse(value, |position, text| ParseError::InvalidNumber {
+        position,
+        text,
+    })
+}
+
+impl OrderRule {
+    fn new(line: Line) -> Result<Self, ParseError> {
+        let Some((first, second)) = line.text.split("|").collect_tuple() else {
+            return Err(ParseError::InvalidRule {
+                position: line.position(line.text),
+                text: line.text.to_string(),
+            });
+        };
+        Ok(OrderRule(
+            parse_number(line, first)?,
+            parse_number(line, second)?,
+        ))
+    }
+
+    pub fn involves(self, value: &usize) -> Option<(Rule, usize)> {
+        match (self.0 == *value, self.1 == *value) {
+            (true, false) => Some((Rule::Before, self.1)),
+            (false, true) => Some((Rule::After, self.0)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleSet {
+    rules: Vec<OrderRule>,
+}
+
+impl RuleSet {
+    fn get_rules_with_value(&self, value: &usize) -> Vec<(Rule, usize)> {
+        self.rules
+            .iter()
+            .filter_map(|rule| rule.involves(value))
+            .collect()
+    }
+
+    fn fulfills_rule(&self, update: &[usize], index: usize, rule: &(Rule, usize)) -> bool {
+        let (rule, other) = rule;
+        let comparison = match rule {
+            Rule::After => |index, pos| pos < index,
+            Rule::Before => |index, pos| pos > index,
+        };
+
+        update
+            .iter()
+            .position(|v| v == other)
+            .is_none_or(|pos| comparison(index, pos))
+    }
+
+    pub fn is_valid_update(&self, update: &[usize]) -> bool {
+        update.iter().enumerate().all(|(i, value)| {
+            self.get_rules_with_value(value)
+                .iter()
+                .all(|rule| self.fulfills_rule(update, i, rule))
+        })
+    }
+
+    pub fn find_failing_index(&self, update: &[usize]) -> Option<(usize, usize)> {
+        for (index, value) in update.iter().enumerate() {
+            let rules = self.get_rules_with_value(value);
+            for (rule, other) in rules {
+                let Some(pos_of_other) = update.iter().position(|v| *v == other) else {
+                    continue;
+                };
+
+                if !match rule {
+                    Rule::After => pos_of_other < index,
+                    Rule::Before => pos_of_other > index,
+                } {
+                    return Some((index, pos_of_other));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Reorders an update so it satisfies every rule, by repeatedly taking the
+/// first page that no page left over has to come before.
+pub fn fix_update(update: &[usize], rules: &RuleSet) -> Result<Vec<usize>, CyclicRules> {
+    let mut remaining = update.to_vec();
+    let mut fixed = Vec::with_capacity(update.len());
+    while !remaining.is_empty() {
+        let next = remaining.iter().position(|page| {
+            rules
+                .get_rules_with_value(page)
+                .iter()
+                .all(|(rule, other)| *rule == Rule::Before || !remaining.contains(other))
+        });
+        match next {
+            Some(index) => fixed.push(remaining.remove(index)),
+            None => return Err(CyclicRules { pages: remaining }),
+        }
+    }
+    Ok(fixed)
+}
+
+fn parse_update(line: Line) -> Result<Vec<usize>, ParseError> {
+    line.text
+        .split(",")
+        .map(|value| parse_number(line, value))
+        .collect()
+}
+
+pub fn part_1(rules: &RuleSet, updates: &[Vec<usize>]) -> usize {
+    updates
+        .iter()
+        .filter(|update| rules.is_valid_update(update))
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
+
+pub fn part_2(rules: &RuleSet, updates: &[Vec<usize>]) -> Result<usize, CyclicRules> {
+    updates
+        .iter()
+        .filter(|update| !rules.is_valid_update(update))
+        .map(|update| fix_update(update, rules).map(|update| update[update.len() / 2]))
+        .sum()
+}
+
+pub fn parse_input(input: &str) -> Result<(RuleSet, Vec<Vec<usize>>), ParseError> {
+    let sections = parsing::sections(input);
+    let Some((rules, updates)) = sections.split_first() else {
+        return Err(ParseError::MissingUpdates);
+    };
+    let rules = rules
+        .iter()
+        .map(|line| OrderRule::new(*line))
+        .collect::<Result<_, _>>()?;
+
+    let updates = updates
+        .iter()
+        .flatten()
+        .map(|line| parse_update(*line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if updates.is_empty() {
+        return Err(ParseError::MissingUpdates);
+    }
+
+    Ok((RuleSet { rules }, updates))
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("47|53\n97-13\n\n75,47,61\n"),
+            Err(ParseError::InvalidRule {
+                position: Position { line: 2, column: 1 },
+                text: "97-13".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input("47|53\n\n75,47,6x1\n"),
+            Err(ParseError::InvalidNumber {
+                position: Position { line: 3, column: 7 },
+                text: "6x1".to_string()
+            })
+        );
+        assert_eq!(parse_input("47|53\n"), Err(ParseError::MissingUpdates));
+    }
+
+    #[test]
+    fn fails_on_cyclic_rules() {
+        let (rules, updates) = parse_input("1|2\n2|1\n2|3\n\n3,2,1\n").unwrap();
+        assert_eq!(
+            part_2(&rules, &updates),
+            Err(CyclicRules {
+                pages: vec![3, 2, 1]
+            })
+        );
+
+        let (rules, _) = parse_input("1|2\n2|3\n\n3,2,1\n").unwrap();
+        assert_eq!(fix_update(&[3, 2, 1], &rules), Ok(vec![1, 2, 3]));
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashSet;
+
+use aoc_core::{Answer, Error, Position, Solution};
+use grid::{Direction, Grid, GridError, Point};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error(transparent)]
+    Map(#[from] GridError),
+    #[error("the map has no guard `^`")]
+    MissingGuard,
+    #[error("second guard `^` at {position}")]
+    SecondGuard { position: Position },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("the guard walks in a loop and never leaves the map")]
+pub struct GuardLoops;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Map;
+
+    type Error = ParseError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        Map::new(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_2(input)?.to_string())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Agent {
+    position: Point,
+    starting_position: Point,
+    direction: Direction,
+    history: HashSet<Point>,
+    history_with_direction: HashSet<(Point, Direction)>,
+}
+
+impl Agent {
+    pub fn new(position: Point) -> Self {
+        Agent {
+            position,
+            starting_position: position,
+            direction: Direction::Up,
+            history: [position].into(),
+            history_with_direction: [].into(),
+        }
+    }
+
+    pub fn position_and_direction(&self) -> (Point, Direction) {
+        (self.position, self.direction)
+    }
+
+    pub fn next_tile(&self) -> Option<Point> {
+        self.position.step(self.direction)
+    }
+
+    pub fn move_forward(&mut self) {
+        self.position = self.next_tile().unwrap();
+        self.history.insert(self.position);
+    }
+
+    /// Returns `false` if the guard has turned this way here before, so it is
+    /// walking in a loop.
+    pub fn turn(&mut self) -> bool {
+        self.direction = self.direction.turn_right();
+        self.history_with_direction
+            .insert(self.position_and_direction())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Map {
+    tiles: Grid<bool>,
+    agent: Agent,
+    loops: HashSet<Point>,
+}
+
+impl Map {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let symbols = Grid::parse(input, |c| matches!(c, '^' | '#' | '.').then_some(c))?;
+        let mut guards = symbols
+            .iter()
+            .filter(|(_, c)| **c == '^')
+            .map(|(point, _)| point);
+        let agent_pos = guards.next().ok_or(ParseError::MissingGuard)?;
+        if let Some(point) = guards.next() {
+            return Err(ParseError::SecondGuard {
+                position: Position::new(point.y, point.x),
+            });
+        }
+
+        Ok(Map {
+            tiles: symbols.map(|c| *c != '#'),
+            agent: Agent::new(agent_pos),
+            loops: [].into(),
+        })
+    }
+
+    fn will_agent_walk_off(&self) -> bool {
+        self.tiles
+            .step(self.agent.position, self.agent.direction)
+            .is_none()
+    }
+
+    fn is_free(&self, tile: Point) -> bool {
+        self.tiles[tile]
+    }
+
+    fn add_obstacle_and_check_loop(&mut self) {
+        let Some(obstacle_location) = self.tiles.step(self.agent.position, self.agent.direction)
+        else {
+            return;
+        };
+
+        if obstacle_location == self.agent.starting_position {
+            return;
+        }
+
+        if self.agent.history.contains(&obstacle_location) {
+            return;
+        }
+
+        let old_agent = self.agent.clone();
+        let mut looping = false;
+
+        let old_tile = self.tiles[obstacle_location];
+        if !old_tile {
+            return;
+        }
+
+        self.tiles[obstacle_location] = false;
+
+        while !self.will_agent_walk_off() {
+            match self.is_free(self.agent.next_tile().unwrap()) {
+                true => self.agent.move_forward(),
+                false => {
+                    if !self.agent.turn() {
+                        looping = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        tracing::trace!(obstacle = ?obstacle_location, looping, "tried obstacle");
+        if looping {
+            self.loops.insert(obstacle_location);
+        }
+
+        self.tiles[obstacle_location] = old_tile;
+        self.agent = old_agent;
+    }
+
+    pub fn simulate(&mut self, add_obstacles: bool) -> Result<(), GuardLoops> {
+        while !self.will_agent_walk_off() {
+            match self.is_free(self.agent.next_tile().unwrap()) {
+                true => self.agent.move_forward(),
+                false if self.agent.turn() => {}
+                false => return Err(GuardLoops),
+            }
+            if add_obstacles {
+                self.add_obstacle_and_check_loop();
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn part_1(map: &Map) -> Result<usize, GuardLoops> {
+    let mut map = map.clone();
+    map.simulate(false)?;
+    Ok(map.agent.history.len())
+}
+
+pub fn part_2(map: &Map) -> Result<usize, GuardLoops> {
+    let mut map = map.clone();
+    map.simulate(true)?;
+    tracing::debug!(loops = map.loops.len(), "tried every obstacle on the route");
+    Ok(map.loops.len())
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            Map::new("..#.\n.^.x\n"),
+            Err(ParseError::Map(GridError::UnknownSymbol {
+                position: Position { line: 2, column: 4 },
+                symbol: 'x'
+            }))
+        );
+        assert_eq!(Map::new("..#.\n....\n"), Err(ParseError::MissingGuard));
+        assert_eq!(
+            Map::new("..#.\n.^.\n"),
+            Err(ParseError::Map(GridError::RaggedRow {
+                line: 2,
+                expected: 4,
+                found: 3
+            }))
+        );
+        assert_eq!(
+            Map::new("..#.\n.^.^\n"),
+            Err(ParseError::SecondGuard {
+                position: Position { line: 2, column: 4 }
+            })
+        );
+    }
+
+    #[test]
+    fn fails_when_the_guard_never_leaves() {
+        let boxed_in = Map::new(".#.\n#^#\n.#.\n").unwrap();
+        assert_eq!(part_1(&boxed_in), Err(GuardLoops));
+
+        let looping = Map::new(".#..\n.^.#\n#...\n..#.\n").unwrap();
+        assert_eq!(part_1(&looping), Err(GuardLoops));
+        assert_eq!(part_2(&looping), Err(GuardLoops));
+    }
+}
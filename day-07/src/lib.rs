@@ -0,0 +1,270 @@
+use std::io::BufRead;
+
+use aoc_core::{Answer, Error, Position, Solution};
+use parsing::{Line, ReadError};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("line {line} is not of the form `total: values`: `{text}`")]
+    MissingTotal { line: usize, text: String },
+    #[error("invalid number `{text}` at {position}")]
+    InvalidNumber { position: Position, text: String },
+    #[error("equation on line {line} has no values")]
+    MissingValues { line: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("the calibration result does not fit in 64 bits")]
+pub struct Overflow;
+
+/// Why [`calibrate`] stopped on a line.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CalibrationError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Equation>;
+
+    type Error = ParseError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_1(input)?.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_2(input)?.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Equation {
+    pub total: u64,
+    pub values: Vec<u64>,
+}
+
+impl Equation {
+    fn new(line: Line) -> Result<Self, ParseError> {
+        let Some((total, values)) = line.text.split_once(":") else {
+            return Err(ParseError::MissingTotal {
+                line: line.number(),
+                text: line.text.to_string(),
+            });
+        };
+
+        let parse = |value| {
+            line.parse(value, |position, text| ParseError::InvalidNumber {
+                position,
+                text,
+            })
+        };
+
+        let total = parse(total)?;
+        let values = values
+            .split_whitespace()
+            .map(parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if values.is_empty() {
+            return Err(ParseError::MissingValues {
+                line: line.number(),
+            });
+        }
+
+        Ok(Equation { total, values })
+    }
+}
+
+fn concatenate(first: u64, second: u64) -> Option<u64> {
+    let shift = 10u64.checked_pow(second.checked_ilog10().unwrap_or(0) + 1)?;
+    first.checked_mul(shift)?.checked_add(second)
+}
+
+/// Every result of combining `values` with `+`, `*` and concatenation, leaving
+/// out the ones that overflow.
+pub fn get_all_combinations(values: &[u64]) -> Vec<u64> {
+    let Some((last, elements)) = values.split_last() else {
+        return vec![];
+    };
+
+    if elements.is_empty() {
+        return vec![*last];
+    }
+
+    get_all_combinations(elements)
+        .iter()
+        .flat_map(|v| {
+            [
+                v.checked_add(*last),
+                v.checked_mul(*last),
+                concatenate(*v, *last),
+            ]
+        })
+        .flatten()
+        .collect()
+}
+
+/// Whether `remaining` can be made from `values` with `+` and `*`.
+pub fn is_possible(remaining: u64, values: &[u64]) -> bool {
+    let Some((last, elements)) = values.split_last() else {
+        return false;
+    };
+
+    if elements.is_empty() {
+        return remaining == *last;
+    }
+
+    let elements = elements.to_vec();
+
+    // Anything times zero is zero.
+    let can_be_divided = match *last {
+        0 => remaining == 0,
+        last => remaining.is_multiple_of(last) && is_possible(remaining / last, &elements),
+    };
+
+    can_be_divided || remaining >= *last && is_possible(remaining - last, &elements)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    parsing::lines(input).map(Equation::new).collect()
+}
+
+fn add(total: u64, value: u64) -> Result<u64, Overflow> {
+    total.checked_add(value).ok_or(Overflow)
+}
+
+pub fn part_1(equations: &[Equation]) -> Result<u64, Overflow> {
+    equations
+        .iter()
+        .filter(|eq| is_possible(eq.total, &eq.values))
+        .try_fold(0, |total, eq| add(total, eq.total))
+}
+
+pub fn part_2(equations: &[Equation]) -> Result<u64, Overflow> {
+    let (part_1_possible, rest): (Vec<_>, Vec<_>) = equations
+        .iter()
+        .partition(|eq| is_possible(eq.total, &eq.values));
+
+    rest.iter()
+        .filter(|eq| get_all_combinations(&eq.values).contains(&eq.total))
+        .chain(part_1_possible.iter())
+        .try_fold(0, |total, eq| add(total, eq.total))
+}
+
+/// The answers to both parts, reading and checking one equation at a time.
+pub fn calibrate(reader: impl BufRead) -> Result<(u64, u64), ReadError<CalibrationError>> {
+    let mut totals = (0, 0);
+    parsing::read_lines(reader, |line| {
+        let eq = Equation::new(line)?;
+        if is_possible(eq.total, &eq.values) {
+            totals.0 = add(totals.0, eq.total)?;
+            totals.1 = add(totals.1, eq.total)?;
+        } else if get_all_combinations(&eq.values).contains(&eq.total) {
+            totals.1 = add(totals.1, eq.total)?;
+        }
+        Ok::<_, CalibrationError>(())
+    })?;
+    Ok(totals)
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Every result of putting `+` or `*` between the values, left to right.
+    fn all_sums_and_products(values: &[u64]) -> Vec<u64> {
+        values[1..].iter().fold(vec![values[0]], |results, value| {
+            results
+                .iter()
+                .flat_map(|result| [result + value, result * value])
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn is_possible_matches_brute_force(
+            values in prop::collection::vec(0..20u64, 1..7),
+            pick in any::<prop::sample::Index>(),
+            offset in 0..2u64,
+        ) {
+            let results = all_sums_and_products(&values);
+            let total = results[pick.index(results.len())] + offset;
+            prop_assert_eq!(is_possible(total, &values), results.contains(&total));
+        }
+    }
+
+    #[test]
+    fn handles_zeros_and_overflow() {
+        assert!(is_possible(0, &[1, 0]));
+        assert!(is_possible(5, &[0, 5]));
+        assert!(!is_possible(1, &[2, 0]));
+        assert_eq!(concatenate(12, 0), Some(120));
+        assert_eq!(concatenate(u64::MAX, 1), None);
+        assert!(get_all_combinations(&[u64::MAX, 2]).is_empty());
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("190: 10 19\n3267: 81 4o 27\n"),
+            Err(ParseError::InvalidNumber {
+                position: Position {
+                    line: 2,
+                    column: 10
+                },
+                text: "4o".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input("190 10 19\n"),
+            Err(ParseError::MissingTotal {
+                line: 1,
+                text: "190 10 19".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input("190: \n"),
+            Err(ParseError::MissingValues { line: 1 })
+        );
+    }
+
+    #[test]
+    fn calibrates_while_reading() {
+        let input = "190: 10 19\n156: 15 6\n83: 17 5\n";
+        let equations = parse_input(input).unwrap();
+        assert_eq!(
+            calibrate(input.as_bytes()).unwrap(),
+            (part_1(&equations).unwrap(), part_2(&equations).unwrap())
+        );
+    }
+
+    #[test]
+    fn reports_overflowing_totals() {
+        let input = "18446744073709551615: 18446744073709551615\n6: 2 3\n";
+        let equations = parse_input(input).unwrap();
+        assert_eq!(part_1(&equations), Err(Overflow));
+        assert_eq!(part_2(&equations), Err(Overflow));
+        assert!(matches!(
+            calibrate(input.as_bytes()),
+            Err(ReadError::Parse(CalibrationError::Overflow(Overflow)))
+        ));
+    }
+}
@@ -0,0 +1,199 @@
+use aoc_core::{Answer, Error, Solution};
+use grid::{Grid, GridError, Point};
+use itertools::*;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+
+    type Error = GridError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        Map::new(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(count_antinodes(input).to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(count_antinodes_with_harmonics(input).to_string())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Antenna {
+    position: Point,
+    symbol: char,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Map {
+    grid: Grid<char>,
+    antennas: Vec<Antenna>,
+}
+
+impl Map {
+    pub fn new(input: &str) -> Result<Self, GridError> {
+        let grid = Grid::parse(input, |c| {
+            (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+        })?;
+        let antennas = grid
+            .iter()
+            .filter(|(_, symbol)| **symbol != '.')
+            .map(|(position, symbol)| Antenna {
+                position,
+                symbol: *symbol,
+            })
+            .collect();
+
+        Ok(Map { grid, antennas })
+    }
+
+    pub fn frequencies(&self) -> Vec<char> {
+        self.antennas.iter().map(|a| a.symbol).unique().collect()
+    }
+
+    pub fn antennas_with_frequency(&self, frequency: &char) -> Vec<&Antenna> {
+        self.antennas
+            .iter()
+            .filter(|a| a.symbol == *frequency)
+            .collect()
+    }
+
+    pub fn in_bounds(&self, point: &Point) -> bool {
+        self.grid.contains(*point)
+    }
+}
+
+fn calculate_antinode(
+    antenna: &Antenna,
+    other: &Antenna,
+    x_dist: usize,
+    y_dist: usize,
+) -> Option<Point> {
+    let (antenna, other) = (antenna.position, other.position);
+    let antinode_x = if antenna.x < other.x {
+        antenna.x.checked_sub(x_dist)
+    } else {
+        Some(antenna.x + x_dist)
+    };
+
+    let antinode_y = if antenna.y < other.y {
+        antenna.y.checked_sub(y_dist)
+    } else {
+        Some(antenna.y + y_dist)
+    };
+
+    Some(Point::new(antinode_x?, antinode_y?))
+}
+
+fn antinode(antenna: &Antenna, other: &Antenna) -> Option<Point> {
+    if antenna == other {
+        return None;
+    }
+
+    let x_dist = antenna.position.x.abs_diff(other.position.x);
+    let y_dist = antenna.position.y.abs_diff(other.position.y);
+
+    calculate_antinode(antenna, other, x_dist, y_dist)
+}
+
+fn antinodes_with_harmonics(antenna: &Antenna, other: &Antenna, map: &Map) -> Vec<Point> {
+    let harmonics_generator = (0..).map(|multiplier| {
+        if antenna == other {
+            return None;
+        }
+
+        let x_dist = antenna.position.x.abs_diff(other.position.x) * multiplier;
+        let y_dist = antenna.position.y.abs_diff(other.position.y) * multiplier;
+
+        calculate_antinode(antenna, other, x_dist, y_dist)
+    });
+
+    harmonics_generator
+        .take_while(|node| node.is_some_and(|node| map.in_bounds(&node)))
+        .flatten()
+        .collect_vec()
+}
+
+fn antinodes_for_antenna(antenna: &Antenna, others: &Vec<&Antenna>) -> Vec<Point> {
+    others
+        .iter()
+        .filter_map(|other| antinode(antenna, other))
+        .collect()
+}
+
+fn antinodes_for_antenna_with_harmonics(
+    antenna: &Antenna,
+    others: &Vec<&Antenna>,
+    map: &Map,
+) -> Vec<Point> {
+    others
+        .iter()
+        .flat_map(|other| antinodes_with_harmonics(antenna, other, map))
+        .collect()
+}
+
+pub fn count_antinodes(map: &Map) -> usize {
+    map.frequencies()
+        .iter()
+        .flat_map(|f| {
+            let antennas = map.antennas_with_frequency(f);
+            antennas
+                .iter()
+                .flat_map(|antenna| antinodes_for_antenna(antenna, &antennas))
+                .filter(|node| map.in_bounds(node))
+                .collect_vec()
+        })
+        .unique()
+        .count()
+}
+
+pub fn count_antinodes_with_harmonics(map: &Map) -> usize {
+    map.frequencies()
+        .iter()
+        .flat_map(|f| {
+            let antennas = map.antennas_with_frequency(f);
+            antennas
+                .iter()
+                .flat_map(|antenna| antinodes_for_antenna_with_harmonics(antenna, &antennas, map))
+                .collect_vec()
+        })
+        .unique()
+        .count()
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            Map::new("....\n.a#.\n"),
+            Err(GridError::UnknownSymbol {
+                position: Position { line: 2, column: 3 },
+                symbol: '#'
+            })
+        );
+        assert_eq!(
+            Map::new("....\n.a.\n"),
+            Err(GridError::RaggedRow {
+                line: 2,
+                expected: 4,
+                found: 3
+            })
+        );
+        assert_eq!(Map::new(""), Err(GridError::Empty));
+    }
+}
@@ -0,0 +1,238 @@
+use std::cmp::min;
+
+use aoc_core::{Answer, Error, Position, Solution};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("the disk map is empty")]
+    Empty,
+    #[error("invalid digit `{symbol}` at {position}")]
+    InvalidDigit { position: Position, symbol: char },
+    #[error("unexpected second disk map on line {line}")]
+    ExtraLine { line: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Free(u8),
+    Taken(usize, u8),
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<u8>;
+
+    type Error = ParseError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(move_blocks(input).to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(move_files(input).to_string())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<u8>, ParseError> {
+    let mut lines = parsing::lines(input);
+    let Some(line) = lines.next() else {
+        return Err(ParseError::Empty);
+    };
+    if let Some(extra) = lines.next() {
+        return Err(ParseError::ExtraLine {
+            line: extra.number(),
+        });
+    }
+
+    line.text
+        .chars()
+        .enumerate()
+        .map(|(x, c)| match c.to_digit(10) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(ParseError::InvalidDigit {
+                position: Position::new(line.index, x),
+                symbol: c,
+            }),
+        })
+        .collect()
+}
+
+fn checksum(files: &[usize]) -> usize {
+    files.iter().enumerate().map(|(i, id)| i * id).sum()
+}
+
+/// Checksum after compacting the disk one block at a time.
+pub fn move_blocks(input: &[u8]) -> usize {
+    let free_spaces = input.iter().skip(1).step_by(2).collect::<Vec<_>>();
+    let file_sizes = input.iter().step_by(2).collect::<Vec<_>>();
+    let input_values = file_sizes
+        .clone()
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, v)| vec![i; (*v).into()])
+        .collect::<Vec<_>>();
+
+    let reverse_input = input_values.clone().into_iter().rev().collect::<Vec<_>>();
+
+    let mut result = vec![];
+
+    let mut i = 0;
+    let mut front_read_values = 0usize;
+    let mut end_read_values = 0usize;
+    let total_size = input_values.len();
+
+    loop {
+        if i == file_sizes.len() || i == free_spaces.len() {
+            break;
+        }
+
+        let Some(file_size) = min(
+            Some((*file_sizes[i]) as usize),
+            total_size
+                .checked_sub(front_read_values)
+                .and_then(|s| s.checked_sub(end_read_values)),
+        ) else {
+            break;
+        };
+
+        result.extend_from_slice(&input_values[front_read_values..(front_read_values + file_size)]);
+
+        front_read_values += file_size;
+        let Some(free_size) = min(
+            Some((*free_spaces[i]) as usize),
+            total_size
+                .checked_sub(front_read_values)
+                .and_then(|s| s.checked_sub(end_read_values)),
+        ) else {
+            break;
+        };
+
+        result.extend_from_slice(&reverse_input[end_read_values..(end_read_values + free_size)]);
+        end_read_values += free_size;
+        i += 1;
+    }
+
+    checksum(&result)
+}
+
+/// Checksum after moving whole files into the leftmost free span.
+pub fn move_files(input: &[u8]) -> usize {
+    let blocks: Vec<_> = input
+        .iter()
+        .enumerate()
+        .map(|(i, size)| {
+            if i % 2 == 0 {
+                Block::Taken(i / 2, *size)
+            } else {
+                Block::Free(*size)
+            }
+        })
+        .filter(|block| match block {
+            Block::Free(size) => *size > 0,
+            _ => true,
+        })
+        .collect();
+
+    let mut result = blocks.clone();
+
+    for block in blocks.into_iter().rev() {
+        let size = match block {
+            Block::Taken(_, size) => size,
+            _ => continue,
+        };
+
+        let free_index = result.iter().position(|block| match block {
+            Block::Free(s) => *s >= size,
+            _ => false,
+        });
+
+        if free_index.is_none() {
+            continue;
+        }
+
+        let index = free_index.unwrap();
+
+        let old_block_pos = result.iter().position(|b| *b == block).unwrap();
+
+        if old_block_pos < index {
+            continue;
+        }
+
+        let old_block = result.remove(old_block_pos);
+        result.insert(old_block_pos, Block::Free(size));
+
+        let old_free = result.remove(index);
+        let space = match old_free {
+            Block::Free(size) => size,
+            _ => continue,
+        };
+
+        let diff = space - size;
+
+        if diff > 0 {
+            result.insert(index, Block::Free(diff));
+        }
+
+        result.insert(index, old_block);
+
+        let mut new_result = vec![];
+        let mut free_size = 0;
+        for block in result {
+            match block {
+                Block::Free(s) => free_size += s,
+                _ => {
+                    if free_size > 0 {
+                        new_result.push(Block::Free(free_size));
+                        free_size = 0;
+                    }
+                    new_result.push(block);
+                }
+            }
+        }
+
+        result = new_result
+    }
+
+    checksum(
+        &result
+            .iter()
+            .flat_map(|block| match block {
+                Block::Free(size) => [0_usize].repeat((*size) as usize),
+                Block::Taken(id, size) => [*id].repeat((*size) as usize),
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("23331x3121\n"),
+            Err(ParseError::InvalidDigit {
+                position: Position { line: 1, column: 6 },
+                symbol: 'x'
+            })
+        );
+        assert_eq!(
+            parse_input("233\n\n12\n"),
+            Err(ParseError::ExtraLine { line: 3 })
+        );
+        assert_eq!(parse_input("\n"), Err(ParseError::Empty));
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use aoc_core::{Answer, Error, Solution};
+use grid::{Grid, GridError, Point};
+
+pub type Map = Grid<u32>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Map;
+
+    type Error = GridError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(trail_scores(input, false).to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(trail_scores(input, true).to_string())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Map, GridError> {
+    Grid::parse(input, |c| c.to_digit(10))
+}
+
+pub fn trailheads(map: &Map) -> Vec<Point> {
+    map.iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(point, _)| point)
+        .collect()
+}
+
+fn one_higher_neighbors(point: Point, map: &Map) -> Vec<Point> {
+    map.neighbors(point)
+        .filter(|n| map[*n].checked_sub(map[point]) == Some(1))
+        .collect()
+}
+
+fn find_peaks(trailhead: Point, map: &Map) -> Vec<Point> {
+    match map[trailhead] {
+        9 => vec![trailhead],
+        _ => one_higher_neighbors(trailhead, map)
+            .into_iter()
+            .flat_map(|neighbour| find_peaks(neighbour, map))
+            .collect(),
+    }
+}
+
+/// Sum over all trailheads of the reachable peaks, or of the distinct paths when `paths` is set.
+pub fn trail_scores(map: &Map, paths: bool) -> usize {
+    let unique_peaks = |h, m| find_peaks(h, m).iter().collect::<HashSet<_>>().len();
+    let unique_paths = |h, m| find_peaks(h, m).len();
+
+    trailheads(map)
+        .into_iter()
+        .map(|trailhead| match paths {
+            true => unique_paths(trailhead, map),
+            false => unique_peaks(trailhead, map),
+        })
+        .sum()
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("0123\n1.34\n"),
+            Err(GridError::UnknownSymbol {
+                position: Position { line: 2, column: 2 },
+                symbol: '.'
+            })
+        );
+        assert_eq!(
+            parse_input("0123\n123\n"),
+            Err(GridError::RaggedRow {
+                line: 2,
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::{collections::HashMap, iter::successors};
+
+use aoc_core::{Answer, Error, Position, Solution, parse_param, unknown_param};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("invalid stone `{text}` at {position}")]
+    InvalidStone { position: Position, text: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("a stone or the number of stones gets too big to count")]
+pub struct Overflow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub part_1_blinks: usize,
+    pub part_2_blinks: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            part_1_blinks: 25,
+            part_2_blinks: 75,
+        }
+    }
+}
+
+impl aoc_core::Config for Config {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "part_1_blinks" => self.part_1_blinks = parse_param(key, value)?,
+            "part_2_blinks" => self.part_2_blinks = parse_param(key, value)?,
+            _ => return Err(unknown_param(key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = (Vec<(usize, usize)>, Config);
+
+    type Error = ParseError;
+
+    type Config = Config;
+
+    fn parse(&self, input: &str, config: &Config) -> Result<Self::Input, Self::Error> {
+        Ok((parse_input(input)?, *config))
+    }
+
+    fn part_1(&self, (stones, config): &Self::Input) -> Result<Answer, Error> {
+        Ok(blink_times(config.part_1_blinks, stones)?.to_string())
+    }
+
+    fn part_2(&self, (stones, config): &Self::Input) -> Result<Answer, Error> {
+        Ok(blink_times(config.part_2_blinks, stones)?.to_string())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    parsing::lines(input)
+        .flat_map(|line| {
+            line.text.split_whitespace().map(move |v| {
+                line.parse(v, |position, text| ParseError::InvalidStone {
+                    position,
+                    text,
+                })
+                .map(|v| (v, 1))
+            })
+        })
+        .collect()
+}
+
+fn split(n: usize, length: usize) -> [usize; 2] {
+    let denominator = 10_usize.pow((length / 2) as u32);
+    [n / denominator, n % denominator]
+}
+
+fn change_stone(stone: (usize, usize)) -> Result<Vec<(usize, usize)>, Overflow> {
+    if stone.0 == 0 {
+        return Ok(vec![(1, stone.1)]);
+    }
+
+    let digits = count_digits(stone.0);
+    if digits.is_multiple_of(2) {
+        let [first, second] = split(stone.0, digits);
+        Ok(vec![(first, stone.1), (second, stone.1)])
+    } else {
+        let multiplied = stone.0.checked_mul(2024).ok_or(Overflow)?;
+        Ok(vec![(multiplied, stone.1)])
+    }
+}
+
+fn count_digits(n: usize) -> usize {
+    successors(Some(n), |&n| (n >= 10).then_some(n / 10)).count()
+}
+
+fn update_multipliers(stones: Vec<(usize, usize)>) -> Result<Vec<(usize, usize)>, Overflow> {
+    let mut map: HashMap<usize, usize> = HashMap::new();
+    for stone in stones {
+        let amount = map.entry(stone.0).or_default();
+        *amount = amount.checked_add(stone.1).ok_or(Overflow)?;
+    }
+    Ok(map
+        .iter()
+        .map(|(stone, amount)| (*stone, *amount))
+        .collect())
+}
+
+/// Number of stones after blinking `n` times.
+pub fn blink_times(n: usize, stones: &[(usize, usize)]) -> Result<usize, Overflow> {
+    let mut res = stones.to_vec();
+    for _ in 0..n {
+        let mut new_stones = vec![];
+        for stone in &res {
+            new_stones.extend(change_stone(*stone)?);
+        }
+        res = update_multipliers(new_stones)?;
+    }
+
+    res.iter()
+        .try_fold(0usize, |total, (_, amount)| total.checked_add(*amount))
+        .ok_or(Overflow)
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Blinks with every stone kept in a list, the way the puzzle tells it.
+    fn blink_literally(n: usize, mut stones: Vec<usize>) -> usize {
+        for _ in 0..n {
+            stones = stones
+                .into_iter()
+                .flat_map(|stone| {
+                    change_stone((stone, 1))
+                        .unwrap()
+                        .into_iter()
+                        .map(|(s, _)| s)
+                })
+                .collect();
+        }
+        stones.len()
+    }
+
+    proptest! {
+        #[test]
+        fn blink_times_matches_literal_stones(
+            stones in prop::collection::vec(0..10_000usize, 1..5),
+            n in 0..15usize,
+        ) {
+            let counted = stones.iter().map(|stone| (*stone, 1)).collect::<Vec<_>>();
+            prop_assert_eq!(blink_times(n, &counted), Ok(blink_literally(n, stones)));
+        }
+    }
+
+    #[test]
+    fn reports_overflow() {
+        // 17 digits, so the stone is multiplied by 2024 past `usize::MAX`.
+        let stones = [(10_000_000_000_000_000, 1)];
+        assert_eq!(blink_times(0, &stones), Ok(1));
+        assert_eq!(blink_times(1, &stones), Err(Overflow));
+        assert_eq!(blink_times(1, &[(1, usize::MAX), (1, 1)]), Err(Overflow));
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("125 17 -3\n"),
+            Err(ParseError::InvalidStone {
+                position: Position { line: 1, column: 8 },
+                text: "-3".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{Answer, Error, Solution};
+use grid::{Direction, Grid, GridError, Point};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Square {
+    letter: char,
+    region: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    plots: Grid<Square>,
+}
+
+impl Map {
+    fn region(&self, point: Point) -> usize {
+        self.plots[point].region
+    }
+
+    /// Whether `other` is on the map and in the same region as `point`.
+    fn same_region(&self, point: Point, other: Option<Point>) -> bool {
+        other.is_some_and(|other| self.region(other) == self.region(point))
+    }
+
+    fn ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.plots.iter().map(|(_, s)| s.region).unique()
+    }
+
+    /// Every region as its plant and the plots it covers, both in reading
+    /// order.
+    pub fn regions(&self) -> impl Iterator<Item = (char, Vec<Point>)> {
+        let mut regions: Vec<(char, Vec<Point>)> = vec![];
+        for (point, square) in self.plots.iter() {
+            // Ids are handed out in reading order, so a new one is always next.
+            if square.region == regions.len() {
+                regions.push((square.letter, vec![]));
+            }
+            regions[square.region].1.push(point);
+        }
+        regions.into_iter()
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Map;
+
+    type Error = GridError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(cost(input).to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(bulk_cost(input).to_string())
+    }
+}
+
+/// Every plot reachable from `start` through plots of the same plant.
+fn get_region(start: Point, map: &Grid<char>) -> Vec<Point> {
+    let region_char = map[start];
+    let mut region = vec![start];
+    let mut seen = HashSet::from([start]);
+    let mut stack = vec![start];
+
+    while let Some(point) = stack.pop() {
+        for neighbor in map.neighbors(point) {
+            if map[neighbor] == region_char && seen.insert(neighbor) {
+                region.push(neighbor);
+                stack.push(neighbor);
+            }
+        }
+    }
+    region
+}
+
+/// Labels every plot with the region it belongs to.
+pub fn parse_input(input: &str) -> Result<Map, GridError> {
+    let grid = Grid::parse(input, |c| c.is_alphabetic().then_some(c))?;
+    let mut region_of = HashMap::new();
+    let mut region_id = 0;
+
+    for point in grid.points() {
+        if !region_of.contains_key(&point) {
+            for square in get_region(point, &grid) {
+                region_of.insert(square, region_id);
+            }
+            region_id += 1;
+        }
+    }
+
+    let plots = Grid::from_fn(grid.width(), grid.height(), |point| Square {
+        letter: grid[point],
+        region: region_of[&point],
+    });
+    Ok(Map { plots })
+}
+
+/// Area of every region by id.
+pub fn areas(map: &Map) -> HashMap<usize, usize> {
+    map.plots.iter().counts_by(|(_, s)| s.region)
+}
+
+/// Perimeter of every region by id: each plot is fenced on every side that
+/// does not touch its own region.
+pub fn perimeters(map: &Map) -> HashMap<usize, usize> {
+    map.plots
+        .iter()
+        .map(|(point, s)| {
+            let inside = map
+                .plots
+                .neighbors(point)
+                .filter(|n| map.region(*n) == s.region)
+                .count();
+            (s.region, 4 - inside)
+        })
+        .into_grouping_map()
+        .sum()
+}
+
+/// Number of sides of every region by id. Each side is counted at the plot
+/// it starts from, the one whose fence does not carry on to its left.
+pub fn sides(map: &Map) -> HashMap<usize, usize> {
+    map.plots
+        .iter()
+        .map(|(point, s)| {
+            let starts = Direction::ALL
+                .into_iter()
+                .filter(|&facing| !map.same_region(point, map.plots.step(point, facing)))
+                .filter(|&facing| {
+                    let along = map.plots.step(point, facing.turn_left());
+                    let fenced = along.is_some_and(|along| {
+                        !map.same_region(point, map.plots.step(along, facing))
+                    });
+                    !(map.same_region(point, along) && fenced)
+                })
+                .count();
+            (s.region, starts)
+        })
+        .into_grouping_map()
+        .sum()
+}
+
+pub fn cost(map: &Map) -> usize {
+    let areas_by_id = areas(map);
+    let perimeters_by_id = perimeters(map);
+
+    map.ids()
+        .map(|id| areas_by_id.get(&id).unwrap_or(&0) * perimeters_by_id.get(&id).unwrap_or(&0))
+        .sum()
+}
+
+pub fn bulk_cost(map: &Map) -> usize {
+    let areas_by_id = areas(map);
+    let sides_by_id = sides(map);
+
+    map.ids()
+        .map(|id| areas_by_id.get(&id).unwrap_or(&0) * sides_by_id.get(&id).unwrap_or(&0))
+        .sum()
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn lists_regions() {
+        let map = parse_input("AAB\nABB\nCCC\n").unwrap();
+        let point = |x, y| Point::new(x, y);
+        assert_eq!(
+            map.regions().collect::<Vec<_>>(),
+            [
+                ('A', vec![point(0, 0), point(1, 0), point(0, 1)]),
+                ('B', vec![point(2, 0), point(1, 1), point(2, 1)]),
+                ('C', vec![point(0, 2), point(1, 2), point(2, 2)]),
+            ]
+        );
+
+        // A region too big to flood fill recursively.
+        let field = "A".repeat(300) + "\n";
+        let map = parse_input(&field.repeat(300)).unwrap();
+        assert_eq!(map.regions().count(), 1);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("AAAA\nBB.C\n"),
+            Err(GridError::UnknownSymbol {
+                position: Position { line: 2, column: 3 },
+                symbol: '.'
+            })
+        );
+        assert_eq!(
+            parse_input("AAAA\nBBC\n"),
+            Err(GridError::RaggedRow {
+                line: 2,
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,448 @@
+use aoc_core::{Answer, Error, Position, Solution, parse_param, unknown_param};
+use parsing::Line;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("expected `{expected}: X.., Y..` on line {line}, found `{text}`")]
+    UnexpectedLine {
+        line: usize,
+        expected: &'static str,
+        text: String,
+    },
+    #[error("invalid coordinate `{text}` at {position}")]
+    InvalidCoordinate { position: Position, text: String },
+    #[error("claw machine ending on line {line} has no `{expected}` line")]
+    MissingLine { line: usize, expected: &'static str },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("the button presses or their cost do not fit in 128 bits")]
+pub struct Overflow;
+
+const LINES: [(&str, &str); 3] = [("Button A", "+"), ("Button B", "+"), ("Prize", "=")];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearEquation {
+    pub a_mul: isize,
+    pub b_mul: isize,
+    pub total: isize,
+}
+
+/// The equations for the X and Y axes of one claw machine.
+pub type System = (LinearEquation, LinearEquation);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// How much further away the prizes really are in part 2.
+    pub offset: isize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            offset: 10000000000000,
+        }
+    }
+}
+
+impl aoc_core::Config for Config {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "offset" => self.offset = parse_param(key, value)?,
+            _ => return Err(unknown_param(key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = (Vec<System>, Config);
+
+    type Error = ParseError;
+
+    type Config = Config;
+
+    fn parse(&self, input: &str, config: &Config) -> Result<Self::Input, Self::Error> {
+        Ok((parse_input(input)?, *config))
+    }
+
+    fn part_1(&self, (systems, _): &Self::Input) -> Result<Answer, Error> {
+        Ok(total_cost(systems, 0)?.to_string())
+    }
+
+    fn part_2(&self, (systems, config): &Self::Input) -> Result<Answer, Error> {
+        Ok(total_cost(systems, config.offset)?.to_string())
+    }
+}
+
+fn parse_line(line: Line, label: &'static str, sign: &str) -> Result<(isize, isize), ParseError> {
+    let unexpected = || ParseError::UnexpectedLine {
+        line: line.number(),
+        expected: label,
+        text: line.text.to_string(),
+    };
+    let rest = line.value(label, ": ").ok_or_else(unexpected)?;
+    let [x, y] = parsing::integers(rest).collect::<Vec<_>>()[..] else {
+        return Err(unexpected());
+    };
+    if rest != format!("X{sign}{x}, Y{sign}{y}") {
+        return Err(unexpected());
+    }
+
+    let coordinate = |value| {
+        line.parse(value, |position, text| ParseError::InvalidCoordinate {
+            position,
+            text,
+        })
+    };
+    Ok((coordinate(x)?, coordinate(y)?))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<System>, ParseError> {
+    parsing::sections(input)
+        .iter()
+        .flat_map(|section| section.chunks(LINES.len()))
+        .map(|machine| {
+            let [a_line, b_line, prize_line] = [0, 1, 2].map(|i| {
+                let (label, sign) = LINES[i];
+                match machine.get(i) {
+                    Some(line) => parse_line(*line, label, sign),
+                    None => Err(ParseError::MissingLine {
+                        line: machine[machine.len() - 1].number(),
+                        expected: label,
+                    }),
+                }
+            });
+            let (a_line, b_line, prize_line) = (a_line?, b_line?, prize_line?);
+
+            Ok((
+                LinearEquation {
+                    a_mul: a_line.0,
+                    b_mul: b_line.0,
+                    total: prize_line.0,
+                },
+                LinearEquation {
+                    a_mul: a_line.1,
+                    b_mul: b_line.1,
+                    total: prize_line.1,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// One equation in 128 bits, with the prize moved by some offset.
+#[derive(Debug, Clone, Copy)]
+struct Wide {
+    a_mul: i128,
+    b_mul: i128,
+    total: i128,
+}
+
+impl Wide {
+    fn new(eq: &LinearEquation, offset: isize) -> Self {
+        Wide {
+            a_mul: eq.a_mul as i128,
+            b_mul: eq.b_mul as i128,
+            total: eq.total as i128 + offset as i128,
+        }
+    }
+}
+
+fn add(x: i128, y: i128) -> Result<i128, Overflow> {
+    x.checked_add(y).ok_or(Overflow)
+}
+
+fn sub(x: i128, y: i128) -> Result<i128, Overflow> {
+    x.checked_sub(y).ok_or(Overflow)
+}
+
+fn mul(x: i128, y: i128) -> Result<i128, Overflow> {
+    x.checked_mul(y).ok_or(Overflow)
+}
+
+/// Whether `value` is a multiple of `by`, which `%` panics on for
+/// `i128::MIN % -1`.
+fn divides(by: i128, value: i128) -> Result<bool, Overflow> {
+    Ok(value.checked_rem(by).ok_or(Overflow)? == 0)
+}
+
+/// Non-negative whole button presses that reach the prize after moving it by
+/// `offset`, if any, the cheapest ones when the buttons move the claw along the
+/// same line.
+pub fn solve_system(
+    (first, second): &System,
+    offset: isize,
+) -> Result<Option<(i128, i128)>, Overflow> {
+    let (first, second) = (Wide::new(first, offset), Wide::new(second, offset));
+    let a_denominator = sub(
+        mul(first.a_mul, second.b_mul)?,
+        mul(first.b_mul, second.a_mul)?,
+    )?;
+    if a_denominator == 0 {
+        return solve_collinear(first, second);
+    }
+
+    let a_numerator = sub(
+        mul(first.total, second.b_mul)?,
+        mul(second.total, first.b_mul)?,
+    )?;
+    if !divides(a_denominator, a_numerator)? {
+        return Ok(None);
+    }
+
+    let a = a_numerator / a_denominator;
+
+    // One of the equations moves with B, or the determinant would be zero.
+    let eq = if second.b_mul != 0 { second } else { first };
+    let b_numerator = sub(eq.total, mul(eq.a_mul, a)?)?;
+    if !divides(eq.b_mul, b_numerator)? {
+        return Ok(None);
+    }
+
+    let b = b_numerator / eq.b_mul;
+    Ok((a >= 0 && b >= 0).then_some((a, b)))
+}
+
+/// When both buttons move along the same line, one axis decides every
+/// solution and the other only has to agree with it.
+fn solve_collinear(first: Wide, second: Wide) -> Result<Option<(i128, i128)>, Overflow> {
+    let (main, other) = match first.a_mul != 0 || first.b_mul != 0 {
+        true => (first, second),
+        false => (second, first),
+    };
+    let Some((a, b)) = cheapest_on_line(main)? else {
+        return Ok(None);
+    };
+    let reached = add(mul(other.a_mul, a)?, mul(other.b_mul, b)?)?;
+    Ok((reached == other.total).then_some((a, b)))
+}
+
+/// The cheapest non-negative whole solution of a single equation. They all lie
+/// on a line, so the cheapest one is at one end of the part where both counts
+/// are non-negative.
+fn cheapest_on_line(eq: Wide) -> Result<Option<(i128, i128)>, Overflow> {
+    let (p, q, total) = (eq.a_mul, eq.b_mul, eq.total);
+    let whole = |value: i128, by: i128| (value % by == 0 && value / by >= 0).then(|| value / by);
+    let solution = match (p, q) {
+        (0, 0) => (total == 0).then_some((0, 0)),
+        (0, q) => whole(total, q).map(|b| (0, b)),
+        (p, 0) => whole(total, p).map(|a| (a, 0)),
+        (p, q) => {
+            let (g, x, y) = extended_gcd(p, q);
+            if total % g != 0 {
+                return Ok(None);
+            }
+            // Every solution is (a0 + step_a * k, b0 - step_b * k).
+            let (a0, b0) = (mul(x, total / g)?, mul(y, total / g)?);
+            let (step_a, step_b) = (q / g, p / g);
+
+            let (mut low, mut high) = (i128::MIN, i128::MAX);
+            // a0 + step_a * k >= 0
+            match step_a > 0 {
+                true => low = low.max(div_ceil(-a0, step_a)),
+                false => high = high.min(div_floor(-a0, step_a)),
+            }
+            // b0 - step_b * k >= 0
+            match step_b > 0 {
+                true => high = high.min(div_floor(b0, step_b)),
+                false => low = low.max(div_ceil(b0, step_b)),
+            }
+            if low > high {
+                return Ok(None);
+            }
+
+            // The cost 3a + b changes by `3 * step_a - step_b` for each step
+            // of k, and the range is bounded on the side it falls towards.
+            let k = match 3 * step_a - step_b > 0 {
+                true => low,
+                false => high,
+            };
+            Some((add(a0, mul(step_a, k)?)?, sub(b0, mul(step_b, k)?)?))
+        }
+    };
+    Ok(solution)
+}
+
+/// `(g, x, y)` with `p * x + q * y = g`, the greatest common divisor.
+fn extended_gcd(p: i128, q: i128) -> (i128, i128, i128) {
+    match q {
+        0 => (p, 1, 0),
+        q => {
+            let (g, x, y) = extended_gcd(q, p % q);
+            (g, y, x - (p / q) * y)
+        }
+    }
+}
+
+fn div_floor(value: i128, by: i128) -> i128 {
+    let quotient = value / by;
+    match value % by != 0 && (value < 0) != (by < 0) {
+        true => quotient - 1,
+        false => quotient,
+    }
+}
+
+fn div_ceil(value: i128, by: i128) -> i128 {
+    -div_floor(-value, by)
+}
+
+/// Tokens needed to win every winnable prize, after moving the prizes by `offset`.
+pub fn total_cost(systems: &[System], offset: isize) -> Result<i128, Overflow> {
+    systems
+        .iter()
+        .try_fold(0, |total, system| match solve_system(system, offset)? {
+            Some((a, b)) => add(total, add(mul(a, 3)?, b)?),
+            None => Ok(total),
+        })
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// The cheapest way to win trying every number of presses up to 100.
+    fn brute_force((first, second): &System) -> Option<(i128, i128)> {
+        (0..=100)
+            .flat_map(|a| (0..=100).map(move |b| (a, b)))
+            .filter(|(a, b)| {
+                [first, second]
+                    .iter()
+                    .all(|eq| eq.a_mul as i128 * a + eq.b_mul as i128 * b == eq.total as i128)
+            })
+            .min_by_key(|(a, b)| a * 3 + b)
+    }
+
+    /// Buttons that move in any direction, or along the same line, including
+    /// ones that do not move at all.
+    fn buttons() -> impl Strategy<Value = [isize; 4]> {
+        prop_oneof![
+            [0..100isize, 0..100isize, 0..100isize, 0..100isize],
+            ([0..10isize, 0..10isize], [0..10isize, 0..10isize]).prop_map(|([dx, dy], [a, b])| [
+                a * dx,
+                a * dy,
+                b * dx,
+                b * dy
+            ]),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn solve_system_matches_brute_force(
+            muls in buttons(),
+            presses in [0..=100isize, 0..=100isize],
+            offset in 0..3isize,
+        ) {
+            let [ax, ay, bx, by] = muls;
+            let [a, b] = presses;
+            let system = (
+                LinearEquation { a_mul: ax, b_mul: bx, total: ax * a + bx * b + offset },
+                LinearEquation { a_mul: ay, b_mul: by, total: ay * a + by * b },
+            );
+
+            let cost = |(a, b): (i128, i128)| a * 3 + b;
+            let solved = solve_system(&system, 0).unwrap();
+            if let Some((a, b)) = solved {
+                prop_assert!(a >= 0 && b >= 0);
+                for eq in [system.0, system.1] {
+                    prop_assert_eq!(eq.a_mul as i128 * a + eq.b_mul as i128 * b, eq.total as i128);
+                }
+            }
+            // Along a line the cheapest way may take more than 100 presses.
+            match (solved, brute_force(&system)) {
+                (Some(solved), Some(slow)) => prop_assert!(cost(solved) <= cost(slow)),
+                (Some((a, b)), None) => prop_assert!(a > 100 || b > 100),
+                (None, slow) => prop_assert_eq!(slow, None),
+            }
+        }
+    }
+
+    #[test]
+    fn solves_collinear_buttons() {
+        let equation = |a_mul, b_mul, total| LinearEquation {
+            a_mul,
+            b_mul,
+            total,
+        };
+        // B moves twice as far for a third of the price.
+        let system = (equation(1, 2, 7), equation(1, 2, 7));
+        assert_eq!(solve_system(&system, 0), Ok(Some((1, 3))));
+        // A is cheaper per step here.
+        let system = (equation(4, 1, 9), equation(4, 1, 9));
+        assert_eq!(solve_system(&system, 0), Ok(Some((2, 1))));
+        // The axes disagree.
+        let system = (equation(1, 1, 2), equation(2, 2, 5));
+        assert_eq!(solve_system(&system, 0), Ok(None));
+        // Neither button moves along X.
+        let system = (equation(0, 0, 0), equation(2, 3, 7));
+        assert_eq!(solve_system(&system, 0), Ok(Some((2, 1))));
+    }
+
+    #[test]
+    fn handles_large_numbers() {
+        let systems = parse_input(
+            "Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X=9223372036854775000, Y=0\n",
+        )
+        .unwrap();
+        assert_eq!(
+            total_cost(&systems, 10000000000000),
+            Ok(3 * 9223382036854775000 + 10000000000000)
+        );
+
+        let systems = parse_input(
+            "Button A: X+4000000000, Y+1\nButton B: X+1, Y+4000000000000\n\
+             Prize: X=4000000001, Y=4000000000001\n",
+        )
+        .unwrap();
+        assert_eq!(total_cost(&systems, 0), Ok(4));
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("Button A: X+94, Y+34\nButton B: X+22, Y-67\nPrize: X=8400, Y=5400\n"),
+            Err(ParseError::UnexpectedLine {
+                line: 2,
+                expected: "Button B",
+                text: "Button B: X+22, Y-67".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input(
+                "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=99999999999999999999, Y=5400\n"
+            ),
+            Err(ParseError::InvalidCoordinate {
+                position: Position {
+                    line: 3,
+                    column: 10
+                },
+                text: "99999999999999999999".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input("Button A: X+94, Y+34\nPrize: X=8400, Y=5400\n"),
+            Err(ParseError::UnexpectedLine {
+                line: 2,
+                expected: "Button B",
+                text: "Prize: X=8400, Y=5400".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input("Button A: X+94, Y+34\nButton B: X+22, Y+67\n"),
+            Err(ParseError::MissingLine {
+                line: 2,
+                expected: "Prize"
+            })
+        );
+    }
+}
@@ -0,0 +1,347 @@
+use std::cmp;
+
+use aoc_core::{Answer, Error, Position, Solution, parse_param, unknown_param};
+use itertools::Itertools;
+use parsing::Line;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("robot on line {line} has no `{field}=` vector")]
+    MissingField { line: usize, field: &'static str },
+    #[error("invalid `{field}=X,Y` vector `{text}` at {position}")]
+    InvalidVector {
+        position: Position,
+        field: &'static str,
+        text: String,
+    },
+    #[error("invalid number `{text}` at {position}")]
+    InvalidNumber { position: Position, text: String },
+    #[error("unexpected `{text}` after the velocity at {position}")]
+    UnexpectedText { position: Position, text: String },
+    #[error("robot on line {line} starts outside the {width}x{height} area")]
+    OutOfBounds {
+        line: usize,
+        width: isize,
+        height: isize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("a robot moves further than 128 bits can count")]
+pub struct Overflow;
+
+#[derive(Debug, Clone, Copy)]
+struct Robot {
+    position: (isize, isize),
+    velocity: (isize, isize),
+}
+
+impl Robot {
+    /// Where the robot is after `steps` moves, wrapping around the edges.
+    fn after(self, steps: usize, width: isize, height: isize) -> Result<Robot, Overflow> {
+        let wrap = |position: isize, velocity: isize, size: isize| {
+            (velocity as i128)
+                .checked_mul(steps as i128)
+                .and_then(|moved| moved.checked_add(position as i128))
+                .map(|moved| moved.rem_euclid(size as i128) as isize)
+                .ok_or(Overflow)
+        };
+        let (x, y) = self.position;
+        let (dx, dy) = self.velocity;
+        Ok(Robot {
+            position: (wrap(x, dx, width)?, wrap(y, dy, height)?),
+            velocity: self.velocity,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    height: isize,
+    width: isize,
+    robots: Vec<Robot>,
+}
+
+impl Map {
+    /// The map after every robot moves `steps` times.
+    fn after(&self, steps: usize) -> Result<Map, Overflow> {
+        Ok(Map {
+            robots: self
+                .robots
+                .iter()
+                .map(|robot| robot.after(steps, self.width, self.height))
+                .collect::<Result<_, _>>()?,
+            ..*self
+        })
+    }
+
+    fn safety_factor(&self) -> usize {
+        let top_left = self
+            .robots
+            .iter()
+            .filter(|r| r.position.0 < self.width / 2 && r.position.1 < self.height / 2)
+            .count();
+
+        let bottom_left = self
+            .robots
+            .iter()
+            .filter(|r| r.position.0 < self.width / 2 && r.position.1 > self.height / 2)
+            .count();
+
+        let top_right = self
+            .robots
+            .iter()
+            .filter(|r| r.position.0 > self.width / 2 && r.position.1 < self.height / 2)
+            .count();
+
+        let bottom_right = self
+            .robots
+            .iter()
+            .filter(|r| r.position.0 > self.width / 2 && r.position.1 > self.height / 2)
+            .count();
+
+        top_left * bottom_left * top_right * bottom_right
+    }
+
+    fn has_line(&self) -> bool {
+        let rows = self.robots.iter().into_group_map_by(|r| r.position.1);
+        rows.values()
+            .map(|row| {
+                row.iter()
+                    .map(|r| r.position.0)
+                    .unique()
+                    .sorted()
+                    .collect_vec()
+            })
+            .any(|row| {
+                let mut line_len = 0;
+                let mut best_line_len = 0;
+                row.windows(2).for_each(|window| {
+                    if window[1] - window[0] == 1 {
+                        line_len += 1;
+                    } else {
+                        best_line_len = cmp::max(best_line_len, line_len);
+                        line_len = 0
+                    }
+                });
+                best_line_len = cmp::max(best_line_len, line_len);
+                best_line_len > 10
+            })
+    }
+
+    pub fn render(&self) -> String {
+        let mut picture = String::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.robots.iter().any(|r| r.position == (x, y)) {
+                    picture.push('█');
+                } else {
+                    picture.push(' ');
+                }
+            }
+            picture.push('\n');
+        }
+        picture
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub width: isize,
+    pub height: isize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            width: 101,
+            height: 103,
+        }
+    }
+}
+
+impl aoc_core::Config for Config {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "width" => self.width = parse_param(key, value)?,
+            "height" => self.height = parse_param(key, value)?,
+            _ => return Err(unknown_param(key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Map;
+
+    type Error = ParseError;
+
+    type Config = Config;
+
+    fn parse(&self, input: &str, config: &Config) -> Result<Self::Input, Self::Error> {
+        parse_input(input, config)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(simulate_steps(100, input)?.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        find_tree(10000, input)?
+            .map(|step| step.to_string())
+            .ok_or_else(|| "no tree within 10000 steps".into())
+    }
+}
+
+fn parse_vector(
+    line: Line,
+    part: Option<&str>,
+    field: &'static str,
+) -> Result<(isize, isize), ParseError> {
+    let part = part.ok_or(ParseError::MissingField {
+        line: line.number(),
+        field,
+    })?;
+    let invalid = || ParseError::InvalidVector {
+        position: line.position(part),
+        field,
+        text: part.to_string(),
+    };
+    let rest = parsing::value(part, field, "=").ok_or_else(invalid)?;
+    let [x, y] = parsing::integers(rest).collect::<Vec<_>>()[..] else {
+        return Err(invalid());
+    };
+    if rest != format!("{x},{y}") {
+        return Err(invalid());
+    }
+
+    let number = |value| {
+        line.parse::<isize, _>(value, |position, text| ParseError::InvalidNumber {
+            position,
+            text,
+        })
+    };
+
+    Ok((number(x)?, number(y)?))
+}
+
+pub fn parse_input(input: &str, config: &Config) -> Result<Map, ParseError> {
+    let Config { width, height } = *config;
+
+    let robots: Vec<_> = parsing::lines(input)
+        .map(|line| {
+            let mut parts = line.text.split_whitespace();
+            let position = parse_vector(line, parts.next(), "p")?;
+            let velocity = parse_vector(line, parts.next(), "v")?;
+            if let Some(extra) = parts.next() {
+                return Err(ParseError::UnexpectedText {
+                    position: line.position(extra),
+                    text: extra.to_string(),
+                });
+            }
+            if !(0..width).contains(&position.0) || !(0..height).contains(&position.1) {
+                return Err(ParseError::OutOfBounds {
+                    line: line.number(),
+                    width,
+                    height,
+                });
+            }
+            Ok(Robot { position, velocity })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Map {
+        width,
+        height,
+        robots,
+    })
+}
+
+/// Safety factor after moving the robots `steps` times.
+pub fn simulate_steps(steps: usize, map: &Map) -> Result<usize, Overflow> {
+    Ok(map.after(steps)?.safety_factor())
+}
+
+/// First step at which the robots draw a picture.
+pub fn find_tree(max_steps: usize, map: &Map) -> Result<Option<usize>, Overflow> {
+    for step in 0..max_steps {
+        if map.after(step)?.has_line() {
+            return Ok(Some(step));
+        }
+    }
+
+    Ok(None)
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: Config = Config {
+        width: 11,
+        height: 7,
+    };
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("p=0,4 v=3,-3\np=6,3 v=-x,-3\n", &EXAMPLE).unwrap_err(),
+            ParseError::InvalidVector {
+                position: Position { line: 2, column: 7 },
+                field: "v",
+                text: "v=-x,-3".to_string()
+            }
+        );
+        assert_eq!(
+            parse_input("p=0,4 v=3,-99999999999999999999\n", &EXAMPLE).unwrap_err(),
+            ParseError::InvalidNumber {
+                position: Position {
+                    line: 1,
+                    column: 11
+                },
+                text: "-99999999999999999999".to_string()
+            }
+        );
+        assert_eq!(
+            parse_input("p=0,4\n", &EXAMPLE).unwrap_err(),
+            ParseError::MissingField {
+                line: 1,
+                field: "v"
+            }
+        );
+        assert_eq!(
+            parse_input("p=0,4 v=3,-3 v=1,1\n", &EXAMPLE).unwrap_err(),
+            ParseError::UnexpectedText {
+                position: Position {
+                    line: 1,
+                    column: 14
+                },
+                text: "v=1,1".to_string()
+            }
+        );
+        assert_eq!(
+            parse_input("p=11,4 v=3,-3\n", &EXAMPLE).unwrap_err(),
+            ParseError::OutOfBounds {
+                line: 1,
+                width: 11,
+                height: 7
+            }
+        );
+    }
+
+    #[test]
+    fn moves_fast_robots() {
+        let map = parse_input("p=2,4 v=-9223372036854775808,0\n", &EXAMPLE).unwrap();
+        // -2^63 is 3 more than a multiple of 11.
+        assert_eq!(map.after(1).unwrap().robots[0].position, (5, 4));
+        assert_eq!(map.after(11).unwrap().robots[0].position, (2, 4));
+        assert!(simulate_steps(usize::MAX, &map).is_ok());
+    }
+}
@@ -0,0 +1,137 @@
+/// The warehouse as given, one tile per box.
+pub mod part_1;
+/// The warehouse twice as wide, with boxes spanning two tiles.
+pub mod part_2;
+
+use aoc_core::{Answer, Error, Position, Solution};
+use grid::{Direction, Grid, GridError, Point};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("no moves after the warehouse map")]
+    MissingMoves,
+    #[error(transparent)]
+    Map(#[from] GridError),
+    #[error("unknown move `{symbol}` at {position}")]
+    UnknownMove { position: Position, symbol: char },
+    #[error("the warehouse map has no robot")]
+    MissingRobot,
+    #[error("expected a wall around the warehouse, found `{symbol}` at {position}")]
+    OpenBorder { position: Position, symbol: char },
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = (part_1::Warehouse, part_2::Warehouse);
+
+    type Error = ParseError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        Ok((part_1::parse_input(input)?, part_2::parse_input(input)?))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        let (initial_position, mut map, moves) = input.0.clone();
+        Ok(part_1::part_1(&mut map, initial_position, moves).to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        let (initial_position, mut map, moves) = input.1.clone();
+        Ok(part_2::part_2(&mut map, initial_position, moves).to_string())
+    }
+}
+
+/// The warehouse map as symbols, the robot position and its moves.
+fn parse_warehouse(input: &str) -> Result<(Grid<char>, Point, Vec<Direction>), ParseError> {
+    let sections = parsing::sections(input);
+    let [map_part, moves_part @ ..] = &sections[..] else {
+        return Err(ParseError::MissingMoves);
+    };
+    if moves_part.is_empty() {
+        return Err(ParseError::MissingMoves);
+    }
+
+    let symbols = Grid::from_lines(map_part.iter().copied(), |c| {
+        matches!(c, '#' | 'O' | '.' | '@').then_some(c)
+    })?;
+    let robot = symbols
+        .position(|c| *c == '@')
+        .ok_or(ParseError::MissingRobot)?;
+    let on_border = |point: Point| {
+        point.x == 0
+            || point.y == 0
+            || point.x == symbols.width() - 1
+            || point.y == symbols.height() - 1
+    };
+    if let Some((point, symbol)) = symbols
+        .iter()
+        .find(|(point, symbol)| on_border(*point) && **symbol != '#')
+    {
+        return Err(ParseError::OpenBorder {
+            position: Position::new(map_part[0].index + point.y, point.x),
+            symbol: *symbol,
+        });
+    }
+
+    let moves = moves_part
+        .iter()
+        .flatten()
+        .flat_map(|line| {
+            line.text.chars().enumerate().map(move |(x, c)| match c {
+                '<' => Ok(Direction::Left),
+                '>' => Ok(Direction::Right),
+                '^' => Ok(Direction::Up),
+                'v' => Ok(Direction::Down),
+                _ => Err(ParseError::UnknownMove {
+                    position: Position::new(line.index, x),
+                    symbol: c,
+                }),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((symbols, robot, moves))
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            Day15.parse("#####\n#.@x#\n#####\n\n<>\n", &()),
+            Err(ParseError::Map(GridError::UnknownSymbol {
+                position: Position { line: 2, column: 4 },
+                symbol: 'x'
+            }))
+        );
+        assert_eq!(
+            Day15.parse("#####\n#.@.#\n#####\n\n<>\n^x\n", &()),
+            Err(ParseError::UnknownMove {
+                position: Position { line: 6, column: 2 },
+                symbol: 'x'
+            })
+        );
+        assert_eq!(
+            Day15.parse("#####\n#...#\n#####\n\n<>\n", &()),
+            Err(ParseError::MissingRobot)
+        );
+        assert_eq!(Day15.parse("#####\n", &()), Err(ParseError::MissingMoves));
+        assert_eq!(
+            Day15.parse("##\n@.\n\n<\n", &()),
+            Err(ParseError::OpenBorder {
+                position: Position { line: 2, column: 1 },
+                symbol: '@'
+            })
+        );
+    }
+}
@@ -1,7 +1,7 @@
-mod part_1;
-mod part_2;
+use std::process::ExitCode;
 
-fn main() {
-    part_1::run();
-    part_2::run();
+use day_15::Day15;
+
+fn main() -> ExitCode {
+    aoc_core::main(&Day15)
 }
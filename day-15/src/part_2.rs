@@ -1,92 +1,47 @@
+use grid::{Direction, Grid, Point};
 use itertools::Itertools;
 
+use crate::{ParseError, parse_warehouse};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum State {
+pub enum State {
     Free,
     BoxLeft,
     BoxRight,
     Wall,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Move {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-type Map = Vec<Vec<State>>;
+pub type Map = Grid<State>;
 
-pub fn run() {
-    let (initial_position, mut map, moves) = parse_input(include_str!("../input.txt"));
-    println!("Part 2: {}", part_2(&mut map, initial_position, moves));
-}
+pub type Warehouse = (Point, Map, Vec<Direction>);
 
-fn parse_input(input: &str) -> ((usize, usize), Map, Vec<Move>) {
-    let mut parts = input.split("\n\n");
-    let map_part = parts.next().unwrap();
-    let moves_part = parts.next().unwrap();
-    let mut robot = (0, 0);
-    let mut check_map = |x, y, c| match c {
-        '#' => [State::Wall, State::Wall],
-        'O' => [State::BoxLeft, State::BoxRight],
-        '.' => [State::Free, State::Free],
-        '@' => {
-            robot = (x * 2, y);
-            [State::Free, State::Free]
+pub fn parse_input(input: &str) -> Result<Warehouse, ParseError> {
+    let (symbols, robot, moves) = parse_warehouse(input)?;
+    let map = Grid::from_fn(symbols.width() * 2, symbols.height(), |point| {
+        match (symbols[Point::new(point.x / 2, point.y)], point.x % 2) {
+            ('#', _) => State::Wall,
+            ('O', 0) => State::BoxLeft,
+            ('O', _) => State::BoxRight,
+            _ => State::Free,
         }
-        _ => panic!("Unexpected map symbol at ({x}, {y}): {c}"),
-    };
-
-    let map = map_part
-        .lines()
-        .filter(|line| !line.is_empty())
-        .enumerate()
-        .map(|(y, col)| {
-            col.chars()
-                .enumerate()
-                .flat_map(|(x, c)| check_map(x, y, c))
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    let moves = moves_part
-        .lines()
-        .flat_map(|line| {
-            line.chars().map(|c| match c {
-                '<' => Move::Left,
-                '>' => Move::Right,
-                '^' => Move::Up,
-                'v' => Move::Down,
-                _ => panic!("Unexpected move symbol: {c}"),
-            })
-        })
-        .collect::<Vec<_>>();
+    });
 
-    (robot, map, moves)
+    Ok((Point::new(robot.x * 2, robot.y), map, moves))
 }
 
-fn get_target(position: (usize, usize), direction: Move) -> (usize, usize) {
-    match direction {
-        Move::Up => (position.0, position.1 - 1),
-        Move::Down => (position.0, position.1 + 1),
-        Move::Left => (position.0 - 1, position.1),
-        Move::Right => (position.0 + 1, position.1),
-    }
+fn get_target(position: Point, direction: Direction) -> Point {
+    position
+        .step(direction)
+        .expect("the warehouse is surrounded by walls")
 }
 
-fn get_boxes_in_front(
-    map: &mut Map,
-    position: (usize, usize),
-    direction: Move,
-) -> Option<Vec<(usize, usize)>> {
+fn get_boxes_in_front(map: &mut Map, position: Point, direction: Direction) -> Option<Vec<Point>> {
     let target = get_target(position, direction);
-    let target_state = map[target.1][target.0];
+    let target_state = map[target];
     let other_target = match target_state {
         State::Free => None,
-        State::BoxLeft => Some((target.0 + 1, target.1)),
-        State::BoxRight => Some((target.0 - 1, target.1)),
+        State::BoxLeft => Some(Point::new(target.x + 1, target.y)),
+        State::BoxRight => Some(Point::new(target.x - 1, target.y)),
         State::Wall => None,
     };
 
@@ -94,67 +49,61 @@ fn get_boxes_in_front(
         return None;
     }
 
-    if other_target.is_some_and(|other| map[other.1][other.0] == State::Wall) {
+    if other_target.is_some_and(|other| map[other] == State::Wall) {
         return None;
     }
 
-    if target_state == State::Free {
-        if other_target.is_none()
-            || other_target.is_some_and(|other| map[other.1][other.0] == State::Free)
-        {
-            return Some(vec![]);
-        }
+    if target_state == State::Free
+        && (other_target.is_none() || other_target.is_some_and(|other| map[other] == State::Free))
+    {
+        return Some(vec![]);
     }
 
-    let Some(box_in_front) = get_boxes_in_front(map, target, direction) else {
-        return None;
-    };
+    let box_in_front = get_boxes_in_front(map, target, direction)?;
 
     if other_target.is_none() {
         return Some(
-            vec![vec![target], box_in_front]
+            [vec![target], box_in_front]
                 .iter()
                 .flatten()
                 .unique()
-                .map(|b| b.clone())
+                .copied()
                 .collect(),
         );
     }
 
     let other_target = other_target.unwrap();
 
-    let Some(other_half) = (if other_target != position {
-        get_boxes_in_front(map, other_target, direction)
+    let other_half = if other_target != position {
+        get_boxes_in_front(map, other_target, direction)?
     } else {
-        Some(vec![])
-    }) else {
-        return None;
+        vec![]
     };
 
     Some(
-        vec![vec![target, other_target], box_in_front, other_half]
+        [vec![target, other_target], box_in_front, other_half]
             .iter()
             .flatten()
             .unique()
-            .map(|b| b.clone())
+            .copied()
             .collect(),
     )
 }
 
-fn try_to_move(map: &mut Map, position: (usize, usize), direction: Move) -> (usize, usize) {
+fn try_to_move(map: &mut Map, position: Point, direction: Direction) -> Point {
     let target = get_target(position, direction);
-    if map[target.1][target.0] == State::Free {
+    if map[target] == State::Free {
         return target;
     }
 
-    if map[target.1][target.0] == State::Wall {
+    if map[target] == State::Wall {
         return position;
     }
 
-    let other_half = if map[target.1][target.0] == State::BoxLeft {
-        (target.0 + 1, target.1)
+    let other_half = if map[target] == State::BoxLeft {
+        Point::new(target.x + 1, target.y)
     } else {
-        (target.0 - 1, target.1)
+        Point::new(target.x - 1, target.y)
     };
 
     let Some(clump_of_boxes) = get_boxes_in_front(map, target, direction) else {
@@ -165,7 +114,7 @@ fn try_to_move(map: &mut Map, position: (usize, usize), direction: Move) -> (usi
         return position;
     };
 
-    let all_boxes = vec![
+    let all_boxes = [
         vec![target, other_half],
         clump_of_boxes,
         other_clump_of_boxes,
@@ -175,7 +124,7 @@ fn try_to_move(map: &mut Map, position: (usize, usize), direction: Move) -> (usi
 
     let new_positions = all_boxes
         .iter()
-        .map(|b| (get_target(**b, direction), map[b.1][b.0]))
+        .map(|b| (get_target(**b, direction), map[**b]))
         .collect_vec();
     let new_free_positions = all_boxes
         .iter()
@@ -183,62 +132,29 @@ fn try_to_move(map: &mut Map, position: (usize, usize), direction: Move) -> (usi
         .collect_vec();
 
     for (pos, state) in new_positions {
-        map[pos.1][pos.0] = state;
+        map[pos] = state;
     }
 
     for pos in new_free_positions {
-        map[pos.1][pos.0] = State::Free;
+        map[**pos] = State::Free;
     }
 
     target
 }
 
-fn run_moves(map: &mut Map, initial_position: (usize, usize), moves: Vec<Move>) {
+fn run_moves(map: &mut Map, initial_position: Point, moves: Vec<Direction>) {
     let mut position = initial_position;
     for direction in moves {
         position = try_to_move(map, position, direction);
     }
 }
 
-fn _print_map(map: &Map, robot_pos: (usize, usize)) {
-    map.iter().enumerate().for_each(|(y, line)| {
-        line.iter().enumerate().for_each(|(x, s)| {
-            if (x, y) == robot_pos {
-                print!("@")
-            } else {
-                match s {
-                    State::Free => print!("."),
-                    State::BoxLeft => print!("["),
-                    State::BoxRight => print!("]"),
-                    State::Wall => print!("#"),
-                }
-            }
-        });
-        println!();
-    });
-}
-
-fn part_2(map: &mut Map, initial_position: (usize, usize), moves: Vec<Move>) -> usize {
+pub fn part_2(map: &mut Map, initial_position: Point, moves: Vec<Direction>) -> usize {
     run_moves(map, initial_position, moves);
     map.iter()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.iter().enumerate().map(move |(x, s)| match s {
-                State::BoxLeft => 100 * y + x,
-                State::BoxRight | State::Free | State::Wall => 0,
-            })
+        .map(|(point, s)| match s {
+            State::BoxLeft => 100 * point.y + point.x,
+            State::BoxRight | State::Free | State::Wall => 0,
         })
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn example_large() {
-        let (initial_position, mut map, moves) =
-            parse_input(include_str!("../input-test-large.txt"));
-        assert!(part_2(&mut map, initial_position, moves) == 9021);
-    }
-}
@@ -0,0 +1,9 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use day_16::Day16;
+
+fn bench(c: &mut Criterion) {
+    aoc_core::criterion(c, &Day16);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,122 @@
+mod part_1;
+mod part_2;
+
+use aoc_core::{Answer, Error, Solution};
+use grid::{Direction, Grid, GridError, Point};
+use thiserror::Error;
+
+pub use part_1::lowest_score;
+pub use part_2::best_seats;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error(transparent)]
+    Map(#[from] GridError),
+    #[error("the maze has no start tile `S`")]
+    MissingStart,
+    #[error("the maze has no end tile `E`")]
+    MissingEnd,
+}
+
+pub type Map = Grid<bool>;
+
+/// A reindeer standing on a tile and facing a direction.
+pub type State = (Point, Direction);
+
+/// Where a reindeer can go next and what it costs: turning on the spot is 1000
+/// points and stepping forward onto a free tile is 1.
+pub fn successors(map: &Map, (point, direction): &State) -> Vec<(State, usize)> {
+    let mut next = vec![
+        ((*point, direction.turn_left()), 1000),
+        ((*point, direction.turn_right()), 1000),
+    ];
+    if let Some(forward) = map.step(*point, *direction).filter(|p| map[*p]) {
+        next.push(((forward, *direction), 1));
+    }
+    next
+}
+
+pub fn parse_input(input: &str) -> Result<(Map, Point, Point), ParseError> {
+    let symbols = Grid::parse(input, |c| matches!(c, '#' | '.' | 'S' | 'E').then_some(c))?;
+    let start = symbols
+        .position(|c| *c == 'S')
+        .ok_or(ParseError::MissingStart)?;
+    let goal = symbols
+        .position(|c| *c == 'E')
+        .ok_or(ParseError::MissingEnd)?;
+
+    Ok((symbols.map(|c| *c != '#'), start, goal))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = (Map, Point, Point);
+
+    type Error = ParseError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        let (map, start, goal) = input;
+        lowest_score(map, *start, *goal)
+            .map(|score| score.to_string())
+            .ok_or_else(|| "the end cannot be reached from the start".into())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        let (map, start, goal) = input;
+        best_seats(map, *start, *goal)
+            .map(|seats| seats.to_string())
+            .ok_or_else(|| "the end cannot be reached from the start".into())
+    }
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn turns_and_steps() {
+        let (map, start, _) = parse_input("#####\n#S.E#\n#####\n").unwrap();
+        let next = successors(&map, &(start, Direction::Right));
+        assert_eq!(
+            next,
+            [
+                ((start, Direction::Up), 1000),
+                ((start, Direction::Down), 1000),
+                ((Point::new(2, 1), Direction::Right), 1)
+            ]
+        );
+        assert_eq!(successors(&map, &(start, Direction::Left)).len(), 2);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("#####\n#S.E#\n##?##\n"),
+            Err(ParseError::Map(GridError::UnknownSymbol {
+                position: Position { line: 3, column: 3 },
+                symbol: '?'
+            }))
+        );
+        assert_eq!(
+            parse_input("#####\n#..E#\n#####\n"),
+            Err(ParseError::MissingStart)
+        );
+        assert_eq!(
+            parse_input("#####\n#S..#\n#####\n"),
+            Err(ParseError::MissingEnd)
+        );
+    }
+}
@@ -0,0 +1,56 @@
+mod part_1;
+mod part_2;
+
+use aoc_core::{Answer, Error, Position, Solution};
+use thiserror::Error;
+
+pub use part_1::{Computer, MAX_STEPS, parse_input, part_1};
+pub use part_2::part_2;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("missing `{expected}` line")]
+    MissingLine { expected: &'static str },
+    #[error("expected `{expected}: ...` on line {line}, found `{text}`")]
+    UnexpectedLine {
+        line: usize,
+        expected: &'static str,
+        text: String,
+    },
+    #[error("invalid register value `{text}` at {position}")]
+    InvalidNumber { position: Position, text: String },
+    #[error("invalid 3-bit program value `{text}` at {position}")]
+    InvalidValue { position: Position, text: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("the program is still running after {MAX_STEPS} steps")]
+pub struct StepLimit;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Computer;
+
+    type Error = ParseError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        Ok(part_1(input)?)
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        part_2(input)?
+            .map(|a| a.to_string())
+            .ok_or_else(|| "no value of register A makes the program output itself".into())
+    }
+}
+
+aoc_core::examples!();
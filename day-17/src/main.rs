@@ -1,10 +1,7 @@
-mod part_1;
-mod part_2;
+use std::process::ExitCode;
 
-use part_1::part_1;
-use part_2::part_2;
+use day_17::Day17;
 
-fn main() {
-    println!("Part 1: {}", part_1());
-    println!("Part 2: {}", part_2());
+fn main() -> ExitCode {
+    aoc_core::main(&Day17)
 }
@@ -1,16 +1,35 @@
 use std::vec;
 
+use parsing::Line;
+
+use crate::{ParseError, StepLimit};
+
+/// How many instructions [`Computer::run`] runs before giving up on a program
+/// that may loop forever.
+pub const MAX_STEPS: usize = 1_000_000;
+
 #[derive(Debug, Clone)]
-struct Computer {
+pub struct Computer {
     a: u64,
     b: u64,
     c: u64,
     p: usize,
-    prog: Vec<u64>,
+    pub prog: Vec<u64>,
     output: Vec<u64>,
 }
 
 impl Computer {
+    pub fn new(a: u64, b: u64, c: u64, prog: Vec<u64>) -> Computer {
+        Computer {
+            a,
+            b,
+            c,
+            p: 0,
+            prog,
+            output: vec![],
+        }
+    }
+
     fn instruction(&self) -> u64 {
         self.prog[self.p]
     }
@@ -19,18 +38,27 @@ impl Computer {
         self.prog[self.p + 1]
     }
 
-    fn combo_operand(&self) -> u64 {
+    /// `None` for the reserved operand 7.
+    fn combo_operand(&self) -> Option<u64> {
         match self.prog[self.p + 1] {
-            0..4 => self.prog[self.p + 1],
-            4 => self.a,
-            5 => self.b,
-            6 => self.c,
-            _ => panic!("Invalid combo operand"),
+            value @ 0..4 => Some(value),
+            4 => Some(self.a),
+            5 => Some(self.b),
+            6 => Some(self.c),
+            _ => None,
         }
     }
 
-    fn adv(&mut self) {
-        self.a /= 2u64.pow(self.combo_operand() as u32);
+    /// `a / 2^operand`, which is 0 once the divisor no longer fits in a u64.
+    fn divide_a(&self, operand: u64) -> u64 {
+        u32::try_from(operand)
+            .ok()
+            .and_then(|shift| self.a.checked_shr(shift))
+            .unwrap_or(0)
+    }
+
+    fn adv(&mut self, operand: u64) {
+        self.a = self.divide_a(operand);
         self.p += 2;
     }
 
@@ -39,8 +67,8 @@ impl Computer {
         self.p += 2;
     }
 
-    fn bst(&mut self) {
-        self.b = self.combo_operand() % 8;
+    fn bst(&mut self, operand: u64) {
+        self.b = operand % 8;
         self.p += 2;
     }
 
@@ -58,39 +86,67 @@ impl Computer {
         self.p += 2;
     }
 
-    fn out(&mut self) {
-        self.output.push(self.combo_operand() % 8);
+    fn out(&mut self, operand: u64) {
+        self.output.push(operand % 8);
         self.p += 2;
     }
 
-    fn bdv(&mut self) {
-        self.b = self.a / 2u64.pow(self.combo_operand() as u32);
+    fn bdv(&mut self, operand: u64) {
+        self.b = self.divide_a(operand);
         self.p += 2;
     }
 
-    fn cdv(&mut self) {
-        self.c = self.a / 2u64.pow(self.combo_operand() as u32);
+    fn cdv(&mut self, operand: u64) {
+        self.c = self.divide_a(operand);
         self.p += 2;
     }
 
-    fn run(&mut self) {
-        let max_pointer: usize = self.prog.len();
-        while self.p < max_pointer - 1 {
-            match self.instruction() {
-                0 => self.adv(),
-                1 => self.bxl(),
-                2 => self.bst(),
-                3 => self.jnz(),
-                4 => self.bxc(),
-                5 => self.out(),
-                6 => self.bdv(),
-                7 => self.cdv(),
-                _ => panic!("Invalid instruction"),
+    /// Runs one instruction, or returns `false` if the computer has halted.
+    /// It halts when the pointer leaves the program, and on an invalid
+    /// instruction or combo operand.
+    pub fn step(&mut self) -> bool {
+        if self.p + 1 >= self.prog.len() {
+            return false;
+        }
+        let combo = self.combo_operand();
+        match (self.instruction(), combo) {
+            (0, Some(operand)) => self.adv(operand),
+            (1, _) => self.bxl(),
+            (2, Some(operand)) => self.bst(operand),
+            (3, _) => self.jnz(),
+            (4, _) => self.bxc(),
+            (5, Some(operand)) => self.out(operand),
+            (6, Some(operand)) => self.bdv(operand),
+            (7, Some(operand)) => self.cdv(operand),
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn run(&mut self) -> Result<(), StepLimit> {
+        for _ in 0..MAX_STEPS {
+            if !self.step() {
+                return Ok(());
             }
         }
+        Err(StepLimit)
+    }
+
+    /// A fresh copy of the computer with register A set to `a`.
+    pub fn with_a(&self, a: u64) -> Computer {
+        Computer {
+            a,
+            p: 0,
+            output: vec![],
+            ..self.clone()
+        }
+    }
+
+    pub fn output(&self) -> &[u64] {
+        &self.output
     }
 
-    fn output_string(&self) -> String {
+    pub fn output_string(&self) -> String {
         self.output
             .iter()
             .map(|v| v.to_string())
@@ -99,55 +155,117 @@ impl Computer {
     }
 }
 
-fn parse_input(input: &str) -> Computer {
-    let mut parts = input.split("\n\n");
-    let mut registers = parts
+fn field<'a>(
+    lines: &mut impl Iterator<Item = Line<'a>>,
+    name: &'static str,
+) -> Result<(Line<'a>, &'a str), ParseError> {
+    let line = lines
         .next()
-        .unwrap()
-        .lines()
-        .filter(|l| !l.is_empty())
-        .map(|l| l.split(": ").skip(1).next().unwrap())
-        .map(|r| r.parse().unwrap());
+        .ok_or(ParseError::MissingLine { expected: name })?;
+    let value = line
+        .value(name, ": ")
+        .ok_or_else(|| ParseError::UnexpectedLine {
+            line: line.number(),
+            expected: name,
+            text: line.text.to_string(),
+        })?;
+    Ok((line, value))
+}
 
-    let a = registers.next().unwrap();
-    let b = registers.next().unwrap();
-    let c = registers.next().unwrap();
+pub fn parse_input(input: &str) -> Result<Computer, ParseError> {
+    let mut lines = parsing::lines(input);
+    let mut register = |name| {
+        let (line, value) = field(&mut lines, name)?;
+        line.parse(value, |position, text| ParseError::InvalidNumber {
+            position,
+            text,
+        })
+    };
 
-    let program = parts
-        .next()
-        .unwrap()
-        .split(": ")
-        .skip(1)
-        .next()
-        .unwrap()
+    let a = register("Register A")?;
+    let b = register("Register B")?;
+    let c = register("Register C")?;
+
+    let (line, values) = field(&mut lines, "Program")?;
+    let program = values
         .split(",")
-        .map(|v| v.parse().unwrap())
-        .collect();
+        .map(|v| match v.parse() {
+            Ok(value) if value < 8 => Ok(value),
+            _ => Err(ParseError::InvalidValue {
+                position: line.position(v),
+                text: v.to_string(),
+            }),
+        })
+        .collect::<Result<_, _>>()?;
 
-    Computer {
-        a,
-        b,
-        c,
-        p: 0,
-        prog: program,
-        output: vec![],
-    }
+    Ok(Computer::new(a, b, c, program))
 }
 
-pub fn part_1() -> String {
-    let mut computer = parse_input(include_str!("../input.txt"));
-    computer.run();
-    computer.output_string()
+pub fn part_1(computer: &Computer) -> Result<String, StepLimit> {
+    let mut computer = computer.clone();
+    computer.run()?;
+    Ok(computer.output_string())
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::Position;
+
     use super::*;
 
     #[test]
-    fn example_1() {
-        let mut computer = parse_input(include_str!("../input-test.txt"));
-        computer.run();
-        assert!(computer.output_string() == "4,6,3,5,6,3,5,2,1,0")
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,8\n")
+                .unwrap_err(),
+            ParseError::InvalidValue {
+                position: Position {
+                    line: 5,
+                    column: 14
+                },
+                text: "8".to_string()
+            }
+        );
+        assert_eq!(
+            parse_input("Register A: 729\nRegister C: 0\n").unwrap_err(),
+            ParseError::UnexpectedLine {
+                line: 2,
+                expected: "Register B",
+                text: "Register C: 0".to_string()
+            }
+        );
+        assert_eq!(
+            parse_input("Register A: 729\nRegister B: 0\nRegister C: 0\n").unwrap_err(),
+            ParseError::MissingLine {
+                expected: "Program"
+            }
+        );
+    }
+
+    #[test]
+    fn halts_on_invalid_programs() {
+        // bst with the reserved combo operand 7.
+        let mut computer = Computer::new(0, 0, 0, vec![5, 1, 2, 7, 5, 1]);
+        computer.run().unwrap();
+        assert_eq!(computer.output(), [1]);
+
+        // adv by 2^65 clears A instead of overflowing.
+        let mut computer = Computer::new(u64::MAX, 65, 0, vec![0, 5, 5, 4]);
+        computer.run().unwrap();
+        assert_eq!(computer.output(), [0]);
+
+        // A jump past the end halts, and so does an odd trailing value.
+        let mut computer = Computer::new(1, 0, 0, vec![3, 7, 5]);
+        assert!(computer.step());
+        assert!(!computer.step());
+    }
+
+    #[test]
+    fn gives_up_on_endless_loops() {
+        let computer =
+            parse_input("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n").unwrap();
+        assert_eq!(part_1(&computer), Err(StepLimit));
+        // Any A but zero loops, so the search for part 2 runs into one too.
+        assert_eq!(crate::part_2(&computer), Err(StepLimit));
     }
 }
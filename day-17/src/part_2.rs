@@ -1,50 +1,29 @@
-fn get_next_output(a_init: usize) -> (usize, usize) {
-    let mut a = a_init;
-    let mut b = (a % 8) ^ 5;
-    let c = a / 2usize.pow(b as u32);
-    b ^= c;
-    b ^= 6;
-    a /= 2usize.pow(3);
-    return (b % 8, a);
-}
-
-fn get_required_output(input: &str) -> Vec<usize> {
-    input
-        .split("\n\n")
-        .skip(1)
-        .next()
-        .unwrap()
-        .split(": ")
-        .skip(1)
-        .next()
-        .unwrap()
-        .split(",")
-        .map(|v| v.parse().unwrap())
-        .collect()
-}
+use crate::{Computer, StepLimit};
 
-fn find_working_a(required_a: usize, p: usize, output: &Vec<usize>) -> Option<usize> {
-    if p == output.len() {
-        return Some(required_a);
+/// Builds register A three bits at a time, matching the program from its last
+/// value backwards. This relies on the program shifting A right by three bits
+/// and printing one value per loop, like every puzzle input does.
+fn find_a(computer: &Computer, a: u64, matched: usize) -> Result<Option<u64>, StepLimit> {
+    let program = &computer.prog;
+    if matched == program.len() {
+        return Ok(Some(a));
     }
 
-    let required_output = output[output.len() - p - 1];
-    let shift = required_a << 3;
-    for i in 0..1 << 10 {
-        let test = shift ^ i;
-        let (out, a) = get_next_output(test);
-        if a == required_a && out == required_output {
-            let res = find_working_a(test, p + 1, output);
-            if res.is_some() {
-                return res;
-            }
+    let expected = &program[program.len() - matched - 1..];
+    for candidate in (0..8).map(|bits| a << 3 | bits) {
+        let mut run = computer.with_a(candidate);
+        run.run()?;
+        if run.output() == expected
+            && let Some(a) = find_a(computer, candidate, matched + 1)?
+        {
+            return Ok(Some(a));
         }
     }
-
-    None
+    Ok(None)
 }
 
-pub fn part_2() -> usize {
-    let output = get_required_output(include_str!("../input.txt"));
-    find_working_a(0, 0, &output).unwrap_or_default()
+/// Lowest value for register A that makes the program output a copy of itself,
+/// if there is one.
+pub fn part_2(computer: &Computer) -> Result<Option<u64>, StepLimit> {
+    find_a(computer, 0, 0)
 }
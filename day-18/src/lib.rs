@@ -0,0 +1,222 @@
+use aoc_core::{Answer, Error, Position, Solution, parse_param, unknown_param};
+use grid::{Grid, Point};
+use itertools::Itertools;
+use pathfinding::bfs;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("invalid byte position `{text}` on line {line}, expected `X,Y`")]
+    InvalidCoordinate { line: usize, text: String },
+    #[error("invalid number `{text}` at {position}")]
+    InvalidNumber { position: Position, text: String },
+    #[error("byte on line {line} falls outside the {size}x{size} memory space")]
+    OutOfBounds { line: usize, size: usize },
+    #[error("the memory space needs a size of at least 1")]
+    EmptySpace,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Width and height of the memory space, the exit is in the far corner.
+    pub size: usize,
+    /// How many bytes have fallen before part 1 looks for a route.
+    pub bytes: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            size: 71,
+            bytes: 1024,
+        }
+    }
+}
+
+impl aoc_core::Config for Config {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "size" => self.size = parse_param(key, value)?,
+            "bytes" => self.bytes = parse_param(key, value)?,
+            _ => return Err(unknown_param(key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = (Vec<Point>, Config);
+
+    type Error = ParseError;
+
+    type Config = Config;
+
+    fn parse(&self, input: &str, config: &Config) -> Result<Self::Input, Self::Error> {
+        Ok((parse_input(input, config)?, *config))
+    }
+
+    fn part_1(&self, (bytes, config): &Self::Input) -> Result<Answer, Error> {
+        let map = create_grid(bytes, config);
+        let route = shortest_route(&map)
+            .ok_or_else(|| format!("the exit cannot be reached after {} bytes", config.bytes))?;
+        Ok((route.len() - 1).to_string())
+    }
+
+    fn part_2(&self, (bytes, config): &Self::Input) -> Result<Answer, Error> {
+        find_first_blocking(bytes, config)
+            .map(|byte| byte.to_string())
+            .ok_or_else(|| "no byte cuts the exit off".into())
+    }
+}
+
+pub fn parse_input(input: &str, config: &Config) -> Result<Vec<Point>, ParseError> {
+    let size = config.size;
+    if size == 0 {
+        return Err(ParseError::EmptySpace);
+    }
+
+    parsing::lines(input)
+        .map(|line| {
+            let (x, y) = line.text.split(",").collect_tuple().ok_or_else(|| {
+                ParseError::InvalidCoordinate {
+                    line: line.number(),
+                    text: line.text.to_string(),
+                }
+            })?;
+            let parse = |value| {
+                line.parse(value, |position, text| ParseError::InvalidNumber {
+                    position,
+                    text,
+                })
+            };
+            let byte = Point::new(parse(x)?, parse(y)?);
+            if byte.x >= size || byte.y >= size {
+                return Err(ParseError::OutOfBounds {
+                    line: line.number(),
+                    size,
+                });
+            }
+            Ok(byte)
+        })
+        .collect()
+}
+
+/// Memory space after the first `config.bytes` bytes have fallen.
+pub fn create_grid(input: &[Point], config: &Config) -> Grid<bool> {
+    let mut map = Grid::new(config.size, config.size, true);
+    input
+        .iter()
+        .take(config.bytes)
+        .for_each(|p| map[*p] = false);
+    map
+}
+
+fn get_neighbors(point: Point, map: &Grid<bool>) -> Vec<Point> {
+    map.neighbors(point).filter(|n| map[*n]).collect()
+}
+
+/// Route from the top left corner to the exit, which there is none of when a
+/// byte has fallen on either of them.
+pub fn shortest_route(map: &Grid<bool>) -> Option<Vec<Point>> {
+    let start = Point::new(0, 0);
+    let goal = Point::new(map.width() - 1, map.height() - 1);
+    if !map[start] || !map[goal] {
+        return None;
+    }
+    bfs(&start, |p| get_neighbors(*p, map), |p| *p == goal)
+}
+
+/// First byte that cuts the exit off from the start, if any does.
+pub fn find_first_blocking(input: &[Point], config: &Config) -> Option<Point> {
+    // Part 1 usually shows the first `config.bytes` leave a route open, so
+    // only start from an empty space when they do not.
+    let mut map = create_grid(input, config);
+    let (mut safe_route, skip) = match shortest_route(&map) {
+        Some(route) => (route, config.bytes),
+        None => {
+            map = create_grid(&[], config);
+            (shortest_route(&map)?, 0)
+        }
+    };
+
+    input
+        .iter()
+        .skip(skip)
+        .find(|p| {
+            map[**p] = false;
+            if safe_route.contains(p) {
+                match shortest_route(&map) {
+                    Some(route) => {
+                        safe_route = route;
+                        false
+                    }
+                    None => true,
+                }
+            } else {
+                false
+            }
+        })
+        .copied()
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exit_never_or_always_blocked() {
+        let config = Config { size: 3, bytes: 2 };
+        assert_eq!(find_first_blocking(&[], &config), None);
+        assert_eq!(find_first_blocking(&[Point::new(1, 0)], &config), None);
+
+        let walls = [Point::new(1, 0), Point::new(1, 1), Point::new(1, 2)];
+        assert!(shortest_route(&create_grid(&walls, &config)).is_some());
+        let config = Config { size: 3, bytes: 3 };
+        assert!(shortest_route(&create_grid(&walls, &config)).is_none());
+        assert_eq!(find_first_blocking(&walls, &config), Some(walls[2]));
+    }
+
+    #[test]
+    fn bytes_on_the_start_or_exit_block_it() {
+        let config = Config { size: 3, bytes: 1 };
+        let start = [Point::new(0, 0)];
+        assert!(shortest_route(&create_grid(&start, &config)).is_none());
+        assert_eq!(find_first_blocking(&start, &config), Some(start[0]));
+
+        let bytes = [Point::new(1, 1), Point::new(2, 2)];
+        assert_eq!(find_first_blocking(&bytes, &config), Some(bytes[1]));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let config = Config::default();
+        assert_eq!(
+            parse_input("5,4\n4,2,1\n", &config),
+            Err(ParseError::InvalidCoordinate {
+                line: 2,
+                text: "4,2,1".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input("5,4\n4,-2\n", &config),
+            Err(ParseError::InvalidNumber {
+                position: Position { line: 2, column: 3 },
+                text: "-2".to_string()
+            })
+        );
+
+        let config = Config { size: 5, bytes: 12 };
+        assert_eq!(
+            parse_input("4,4\n5,4\n", &config),
+            Err(ParseError::OutOfBounds { line: 2, size: 5 })
+        );
+        let config = Config { size: 0, bytes: 12 };
+        assert_eq!(parse_input("", &config), Err(ParseError::EmptySpace));
+    }
+}
@@ -0,0 +1,244 @@
+use std::{collections::HashMap, io::BufRead};
+
+use aoc_core::{Answer, Error, Position, Solution};
+use parsing::{Line, ReadError};
+use thiserror::Error;
+
+const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("no towel patterns in the input")]
+    MissingTowels,
+    #[error("empty towel pattern at {position}")]
+    EmptyTowel { position: Position },
+    #[error("unknown stripe color `{symbol}` at {position}")]
+    UnknownColor { position: Position, symbol: char },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("the number of ways to make the designs does not fit in 64 bits")]
+pub struct Overflow;
+
+/// Why [`count_possible_from`] stopped on a line.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CountError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    /// Both parts come out of one pass over the designs, so it is made once
+    /// while parsing.
+    type Input = Result<(usize, usize), Overflow>;
+
+    type Error = ParseError;
+
+    type Config = ();
+
+    fn parse(&self, input: &str, _: &()) -> Result<Self::Input, Self::Error> {
+        let (towels, designs) = parse_input(input)?;
+        Ok(count_possible(&towels, &designs))
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        let (possible, _) = (*input)?;
+        Ok(possible.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        let (_, ways) = (*input)?;
+        Ok(ways.to_string())
+    }
+}
+
+fn parse_stripes(line: Line, stripes: &str) -> Result<String, ParseError> {
+    let position = line.position(stripes);
+    if stripes.is_empty() {
+        return Err(ParseError::EmptyTowel { position });
+    }
+    match stripes
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !COLORS.contains(c))
+    {
+        Some((x, symbol)) => Err(ParseError::UnknownColor {
+            position: Position {
+                column: position.column + x,
+                ..position
+            },
+            symbol,
+        }),
+        None => Ok(stripes.to_string()),
+    }
+}
+
+/// The towel patterns, longest first.
+fn parse_towels(line: Line) -> Result<Vec<String>, ParseError> {
+    let mut towels = line
+        .text
+        .split(", ")
+        .map(|towel| parse_stripes(line, towel))
+        .collect::<Result<Vec<_>, _>>()?;
+    towels.sort_by_key(|towel| std::cmp::Reverse(towel.len()));
+    Ok(towels)
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let mut lines = parsing::lines(input);
+    let towels = parse_towels(lines.next().ok_or(ParseError::MissingTowels)?)?;
+    let designs = lines
+        .map(|line| parse_stripes(line, line.text))
+        .collect::<Result<_, _>>()?;
+    Ok((towels, designs))
+}
+
+fn match_recursive(
+    design: String,
+    towels: &[String],
+    cache: &mut HashMap<String, usize>,
+) -> Result<usize, Overflow> {
+    if design.is_empty() {
+        return Ok(1);
+    }
+
+    if cache.contains_key(&design) {
+        return Ok(*cache.get(&design).unwrap());
+    }
+
+    let possible: Vec<String> = towels
+        .iter()
+        .filter(|t| design.contains(*t))
+        .map(|t| t.to_string())
+        .collect();
+
+    let res = possible
+        .iter()
+        .filter(|towel| design.starts_with(*towel))
+        .try_fold(0usize, |total, towel| {
+            let ways = match_recursive(
+                design.strip_prefix(towel).unwrap().to_string(),
+                &possible,
+                cache,
+            )?;
+            total.checked_add(ways).ok_or(Overflow)
+        })?;
+
+    cache.insert(design, res);
+    Ok(res)
+}
+
+fn ways(
+    design: &str,
+    towels: &[String],
+    cache: &mut HashMap<String, usize>,
+) -> Result<usize, Overflow> {
+    let possible: Vec<String> = towels
+        .iter()
+        .filter(|t| design.contains(*t))
+        .map(|t| t.to_string())
+        .collect();
+
+    match_recursive(design.to_string(), &possible, cache)
+}
+
+/// Number of designs that can be made and the total number of ways to make them.
+pub fn count_possible(towels: &[String], designs: &[String]) -> Result<(usize, usize), Overflow> {
+    let mut cache: HashMap<String, usize> = HashMap::new();
+    let possible = designs
+        .iter()
+        .map(|d| ways(d, towels, &mut cache))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let total = possible
+        .iter()
+        .try_fold(0usize, |total, ways| total.checked_add(*ways))
+        .ok_or(Overflow)?;
+    Ok((possible.iter().filter(|p| **p > 0).count(), total))
+}
+
+/// Like [`count_possible`], reading the designs one at a time. The cache only
+/// lives for one design so that memory stays flat on long inputs.
+pub fn count_possible_from(reader: impl BufRead) -> Result<(usize, usize), ReadError<CountError>> {
+    let mut towels = None;
+    let mut cache = HashMap::new();
+    let mut counts: (usize, usize) = (0, 0);
+    parsing::read_lines(reader, |line| {
+        let Some(towels) = &towels else {
+            towels = Some(parse_towels(line)?);
+            return Ok(());
+        };
+        let ways = ways(&parse_stripes(line, line.text)?, towels, &mut cache)?;
+        cache.clear();
+        counts.0 += (ways > 0) as usize;
+        counts.1 = counts.1.checked_add(ways).ok_or(Overflow)?;
+        Ok::<_, CountError>(())
+    })?;
+
+    match towels {
+        Some(_) => Ok(counts),
+        None => Err(ReadError::Parse(ParseError::MissingTowels.into())),
+    }
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("r, wr, b\n\nbrwrr\nbggxr\n"),
+            Err(ParseError::UnknownColor {
+                position: Position { line: 4, column: 4 },
+                symbol: 'x'
+            })
+        );
+        assert_eq!(
+            parse_input("r, , b\n\nbrwrr\n"),
+            Err(ParseError::EmptyTowel {
+                position: Position { line: 1, column: 4 }
+            })
+        );
+        assert_eq!(parse_input("\n"), Err(ParseError::MissingTowels));
+    }
+
+    #[test]
+    fn counts_while_reading() {
+        let input = "r, wr, b, g, bwu, rb, gb, br\n\nbrwrr\nbggr\nubwu\n";
+        let (towels, designs) = parse_input(input).unwrap();
+        assert_eq!(
+            count_possible_from(input.as_bytes()).unwrap(),
+            count_possible(&towels, &designs).unwrap()
+        );
+        assert!(matches!(
+            count_possible_from("\n".as_bytes()),
+            Err(ReadError::Parse(CountError::Parse(
+                ParseError::MissingTowels
+            )))
+        ));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        // The ways to make a run of `w`s from `w` and `ww` are Fibonacci
+        // numbers, and the 100th does not fit in 64 bits.
+        let input = format!("w, ww\n\n{}\n", "w".repeat(100));
+        let (towels, designs) = parse_input(&input).unwrap();
+        assert_eq!(count_possible(&towels, &designs), Err(Overflow));
+        assert!(matches!(
+            count_possible_from(input.as_bytes()),
+            Err(ReadError::Parse(CountError::Overflow(Overflow)))
+        ));
+        let input = format!("w, ww\n\n{}\n", "w".repeat(90));
+        assert!(count_possible_from(input.as_bytes()).is_ok());
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use aoc_core::{Answer, Error, Solution, parse_param, unknown_param};
+use grid::{Grid, GridError, Point};
+use itertools::Itertools;
+use pathfinding::astar;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error(transparent)]
+    Map(#[from] GridError),
+    #[error("the racetrack has no start tile `S`")]
+    MissingStart,
+    #[error("the racetrack has no end tile `E`")]
+    MissingEnd,
+}
+
+pub type Map = Grid<bool>;
+type ShortcutMap = HashMap<(Point, Point), usize>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Only cheats that save at least this many picoseconds are counted.
+    pub min_saving: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { min_saving: 100 }
+    }
+}
+
+impl aoc_core::Config for Config {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        match key {
+            "min_saving" => self.min_saving = parse_param(key, value)?,
+            _ => return Err(unknown_param(key)),
+        }
+        Ok(())
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = ((Map, Point, Point), Config);
+
+    type Error = ParseError;
+
+    type Config = Config;
+
+    fn parse(&self, input: &str, config: &Config) -> Result<Self::Input, Self::Error> {
+        Ok((parse_input(input)?, *config))
+    }
+
+    fn part_1(&self, (racetrack, config): &Self::Input) -> Result<Answer, Error> {
+        count_shortcuts(racetrack, 2, config.min_saving)
+            .map(|count| count.to_string())
+            .ok_or_else(|| "the end cannot be reached from the start".into())
+    }
+
+    fn part_2(&self, (racetrack, config): &Self::Input) -> Result<Answer, Error> {
+        count_shortcuts(racetrack, 20, config.min_saving)
+            .map(|count| count.to_string())
+            .ok_or_else(|| "the end cannot be reached from the start".into())
+    }
+}
+
+/// Number of cheats of at most `length` that save at least `min_saving`, or
+/// `None` when there is no route to cheat on.
+pub fn count_shortcuts(
+    (map, start, end): &(Map, Point, Point),
+    length: usize,
+    min_saving: usize,
+) -> Option<usize> {
+    let route = a_star(map, start, end)?;
+    Some(check_shortcuts(map, &route, length, min_saving).len())
+}
+
+fn get_neighbors(point: Point, map: &Map) -> Vec<Point> {
+    map.neighbors(point).filter(|n| map[*n]).collect()
+}
+
+fn tiles_within_distance(current: Point, range: usize, map: &Map) -> Vec<Point> {
+    let range = range as isize;
+    (-range..=range)
+        .flat_map(|dy| {
+            let width = range - dy.abs();
+            (-width..=width).filter_map(move |dx| current.offset(dx, dy))
+        })
+        .filter(|point| map.contains(*point))
+        .collect_vec()
+}
+
+fn get_possible_shortcuts(
+    map: &Map,
+    current: &Point,
+    route: &[Point],
+    max_length: usize,
+) -> Vec<(Point, Point)> {
+    let current_index = route.iter().position(|p| p == current).unwrap();
+    let index_on_path = |point: &Point| route.iter().position(|p| p == point);
+
+    tiles_within_distance(*current, max_length, map)
+        .into_iter()
+        .filter(|point| {
+            map[*point] && index_on_path(point).is_some_and(|index| index > current_index)
+        })
+        .map(|p| (*current, p))
+        .collect_vec()
+}
+
+pub fn a_star(map: &Map, start: &Point, goal: &Point) -> Option<Vec<Point>> {
+    astar(
+        start,
+        |p| get_neighbors(*p, map).into_iter().map(|n| (n, 1)),
+        |p| goal.manhattan_distance(*p),
+        |p| p == goal,
+    )
+    .map(|(_, path)| path)
+}
+
+pub fn parse_input(input: &str) -> Result<(Map, Point, Point), ParseError> {
+    let symbols = Grid::parse(input, |c| matches!(c, '#' | '.' | 'S' | 'E').then_some(c))?;
+    let start = symbols
+        .position(|c| *c == 'S')
+        .ok_or(ParseError::MissingStart)?;
+    let goal = symbols
+        .position(|c| *c == 'E')
+        .ok_or(ParseError::MissingEnd)?;
+
+    Ok((symbols.map(|c| *c != '#'), start, goal))
+}
+
+fn check_shortcuts(
+    map: &Map,
+    route: &Vec<Point>,
+    max_shortcut_length: usize,
+    min_saved_time: usize,
+) -> ShortcutMap {
+    let shortcuts = Arc::new(Mutex::new(HashMap::new()));
+    let evaluated = AtomicUsize::new(0);
+
+    route.par_iter().for_each(|point| {
+        let candidates = get_possible_shortcuts(map, point, route, max_shortcut_length);
+        tracing::trace!(
+            ?point,
+            candidates = candidates.len(),
+            "evaluating shortcuts"
+        );
+        evaluated.fetch_add(candidates.len(), Ordering::Relaxed);
+
+        for new_shortcut in candidates {
+            let route_around = a_star(map, point, &new_shortcut.1).unwrap_or_default();
+            let saved_time = route_around.len() - point.manhattan_distance(new_shortcut.1);
+
+            if saved_time < min_saved_time {
+                continue;
+            }
+
+            {
+                let mut locked_shortcuts = shortcuts.lock().unwrap();
+                locked_shortcuts.insert(new_shortcut, saved_time);
+            }
+        }
+    });
+
+    let shortcuts = shortcuts.lock().unwrap().clone();
+    tracing::debug!(
+        route = route.len(),
+        evaluated = evaluated.into_inner(),
+        kept = shortcuts.len(),
+        "checked shortcuts"
+    );
+    shortcuts
+}
+
+aoc_core::examples!();
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(
+            parse_input("#####\n#S.E#\n#.o.#\n"),
+            Err(ParseError::Map(GridError::UnknownSymbol {
+                position: Position { line: 3, column: 3 },
+                symbol: 'o'
+            }))
+        );
+        assert_eq!(
+            parse_input("#####\n#S..#\n#####\n"),
+            Err(ParseError::MissingEnd)
+        );
+    }
+
+    #[test]
+    fn unreachable_end() {
+        let racetrack = parse_input("#####\n#S#E#\n#####\n").unwrap();
+        assert_eq!(count_shortcuts(&racetrack, 2, 1), None);
+    }
+}
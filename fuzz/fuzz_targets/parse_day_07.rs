@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_core::Solution;
+use day_07::Day07;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day07.parse(input, &Default::default());
+});
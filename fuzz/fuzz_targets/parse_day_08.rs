@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_core::Solution;
+use day_08::Day08;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day08.parse(input, &Default::default());
+});
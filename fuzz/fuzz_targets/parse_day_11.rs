@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_core::Solution;
+use day_11::Day11;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day11.parse(input, &Default::default());
+});
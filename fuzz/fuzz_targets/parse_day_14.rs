@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_core::Solution;
+use day_14::Day14;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day14.parse(input, &Default::default());
+});
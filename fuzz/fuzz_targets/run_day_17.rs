@@ -0,0 +1,21 @@
+#![no_main]
+
+use day_17::Computer;
+use libfuzzer_sys::fuzz_target;
+
+/// Programs can loop forever, so each run is cut off after this many steps.
+const MAX_STEPS: usize = 10_000;
+
+fuzz_target!(|input: ((u64, u64, u64), Vec<u8>)| {
+    let ((a, b, c), program) = input;
+    let program = program
+        .into_iter()
+        .map(|value| u64::from(value % 8))
+        .collect();
+    let mut computer = Computer::new(a, b, c, program);
+    for _ in 0..MAX_STEPS {
+        if !computer.step() {
+            break;
+        }
+    }
+});
@@ -0,0 +1,228 @@
+mod point;
+
+use std::ops::{Index, IndexMut};
+
+use parsing::{Cells, Line};
+
+pub use parsing::GridError;
+pub use point::{Direction, NEIGHBORHOOD_8, Point};
+
+/// A rectangular map of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn from_chars(input: &str) -> Result<Self, GridError> {
+        Grid::parse(input, Some)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a map with one cell per character, skipping blank lines. `cell`
+    /// returns `None` for symbols that do not belong on the map. This is
+    /// [`parsing::char_grid`] over [`parsing::lines`], so days that only read
+    /// a map need no `parsing` dependency of their own.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        Grid::from_lines(parsing::lines(input), cell)
+    }
+
+    /// Like [`Grid::parse`], for a map that is one section of the input.
+    pub fn from_lines<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridError> {
+        let Cells {
+            width,
+            height,
+            cells,
+        } = parsing::char_grid(lines, cell)?;
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// The next point in `direction`, if it is still on the map.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        point.step(direction).filter(|next| self.contains(*next))
+    }
+
+    /// The up to 4 orthogonally adjacent points on the map.
+    pub fn neighbors(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The up to 8 adjacent points on the map, diagonals included.
+    pub fn neighbors_8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORHOOD_8
+            .into_iter()
+            .filter_map(move |(dx, dy)| point.offset(dx, dy))
+            .filter(|next| self.contains(*next))
+    }
+
+    /// Every point on the map, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the map back as text, one line per row.
+    pub fn render(&self, mut symbol: impl FnMut(Point, &T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for (point, cell) in self.iter() {
+            text.push(symbol(point, cell));
+            if point.x + 1 == self.width {
+                text.push('\n');
+            }
+        }
+        text
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{point:?} is outside the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the {width}x{height} grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = Grid::parse("#.#\n..#\n", |c| match c {
+            '#' => Some(false),
+            '.' => Some(true),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[Point::new(1, 0)]);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(
+            grid.render(|_, free| if *free { '.' } else { '#' }),
+            "#.#\n..#\n"
+        );
+    }
+
+    #[test]
+    fn invalid_maps() {
+        assert_eq!(
+            Grid::from_chars("abc\nab\n"),
+            Err(GridError::RaggedRow {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Grid::parse("..\n.x\n", |c| (c == '.').then_some(c)),
+            Err(GridError::UnknownSymbol {
+                position: Position { line: 2, column: 2 },
+                symbol: 'x'
+            })
+        );
+        assert_eq!(Grid::from_chars("\n"), Err(GridError::Empty));
+        assert_eq!(Grid::from_chars("ab \r\nab\r\n").unwrap().width(), 2);
+    }
+
+    #[test]
+    fn neighbors_stay_on_the_map() {
+        let grid = Grid::new(3, 3, 0);
+        let corner = grid.neighbors(Point::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors_8(Point::new(2, 2)).count(), 3);
+    }
+}
@@ -0,0 +1,276 @@
+use std::{
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+use aoc_core::Position;
+use thiserror::Error;
+
+/// One line of a puzzle input, without its line ending or trailing
+/// whitespace, and with the index it had in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// The 1-based line number, for error messages.
+    pub fn number(self) -> usize {
+        self.index + 1
+    }
+
+    /// Position of `fragment`, which has to be a slice of the line.
+    pub fn position(self, fragment: &str) -> Position {
+        Position::of(self.index, self.text, fragment)
+    }
+
+    /// Parses `fragment`, a slice of the line. On failure `error` gets the
+    /// position and text of the fragment.
+    pub fn parse<T: FromStr, E>(
+        self,
+        fragment: &str,
+        error: impl FnOnce(Position, String) -> E,
+    ) -> Result<T, E> {
+        fragment
+            .parse()
+            .map_err(|_| error(self.position(fragment), fragment.to_string()))
+    }
+
+    /// The value of a `key{separator}value` line, like `729` in
+    /// `Register A: 729`.
+    pub fn value(self, key: &str, separator: &str) -> Option<&'a str> {
+        value(self.text, key, separator)
+    }
+}
+
+/// The value of a `key{separator}value` fragment, like `0,4` in `p=0,4`.
+pub fn value<'a>(text: &'a str, key: &str, separator: &str) -> Option<&'a str> {
+    text.strip_prefix(key)?.strip_prefix(separator)
+}
+
+/// Every line of the input that is not blank. Handles `\r\n` line endings and
+/// ignores trailing whitespace.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .map(str::trim_end)
+        .enumerate()
+        .filter(|(_, text)| !text.is_empty())
+        .map(|(index, text)| Line { index, text })
+}
+
+/// Why reading an input from a [`BufRead`] stopped.
+#[derive(Debug, Error)]
+pub enum ReadError<E> {
+    #[error("could not read the input: {0}")]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Parse(E),
+}
+
+/// Hands the lines of `reader` that are not blank to `f`, numbered and trimmed
+/// like [`lines`], holding only one line in memory at a time.
+pub fn read_lines<E>(
+    mut reader: impl BufRead,
+    mut f: impl FnMut(Line) -> Result<(), E>,
+) -> Result<(), ReadError<E>> {
+    let mut buffer = String::new();
+    for index in 0.. {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+        let text = buffer.trim_end();
+        if !text.is_empty() {
+            f(Line { index, text }).map_err(ReadError::Parse)?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GridError {
+    #[error("the map is empty")]
+    Empty,
+    #[error("line {line} has {found} tiles, expected {expected}")]
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("unknown map symbol `{symbol}` at {position}")]
+    UnknownSymbol { position: Position, symbol: char },
+}
+
+/// The cells of a rectangular map, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cells<T> {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<T>,
+}
+
+/// Reads a map with one cell per character. `cell` returns `None` for symbols
+/// that do not belong on the map. Days with a map read it through
+/// `grid::Grid::parse`, which is built on this.
+pub fn char_grid<'a, T>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Cells<T>, GridError> {
+    let mut width = None;
+    let mut height = 0;
+    let mut cells = Vec::new();
+
+    for line in lines {
+        let row_start = cells.len();
+        for (x, symbol) in line.text.chars().enumerate() {
+            cells.push(cell(symbol).ok_or(GridError::UnknownSymbol {
+                position: Position::new(line.index, x),
+                symbol,
+            })?);
+        }
+
+        let found = cells.len() - row_start;
+        let expected = *width.get_or_insert(found);
+        if found != expected {
+            return Err(GridError::RaggedRow {
+                line: line.number(),
+                expected,
+                found,
+            });
+        }
+        height += 1;
+    }
+
+    match width {
+        Some(width) => Ok(Cells {
+            width,
+            height,
+            cells,
+        }),
+        None => Err(GridError::Empty),
+    }
+}
+
+/// Groups of lines separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections: Vec<Vec<Line>> = vec![];
+    let mut previous = None;
+    for line in lines(input) {
+        match (previous, sections.last_mut()) {
+            (Some(previous), Some(section)) if previous + 1 == line.index => section.push(line),
+            _ => sections.push(vec![line]),
+        }
+        previous = Some(line.index);
+    }
+    sections
+}
+
+/// Every integer in `text`, with its sign, as slices that can be parsed and
+/// located with [`Line::parse`].
+pub fn integers(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let start = rest.find(|c: char| c.is_ascii_digit())?;
+        let start = match rest[..start].ends_with('-') {
+            true => start - 1,
+            false => start,
+        };
+        let digits = rest[start + 1..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(rest.len(), |end| start + 1 + end);
+        let (integer, after) = (&rest[start..digits], &rest[digits..]);
+        rest = after;
+        Some(integer)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_lines_and_sections() {
+        let input = "47|53\r\n97|13  \r\n\r\n \r\n75,47\r\n";
+        let sections = sections(input);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(
+            sections[0],
+            [
+                Line {
+                    index: 0,
+                    text: "47|53"
+                },
+                Line {
+                    index: 1,
+                    text: "97|13"
+                }
+            ]
+        );
+        assert_eq!(sections[1][0].number(), 5);
+        assert_eq!(lines("a\n\nb").count(), 2);
+    }
+
+    #[test]
+    fn reads_lines_one_at_a_time() {
+        let input = "3   4\r\n\n4   3  \n";
+        let mut read = vec![];
+        read_lines(input.as_bytes(), |line| {
+            read.push((line.index, line.text.to_string()));
+            Ok::<_, ()>(())
+        })
+        .unwrap();
+        let expected: Vec<_> = lines(input)
+            .map(|line| (line.index, line.text.to_string()))
+            .collect();
+        assert_eq!(read, expected);
+
+        let stopped = read_lines(input.as_bytes(), |line| Err(line.number()));
+        assert!(matches!(stopped, Err(ReadError::Parse(1))));
+    }
+
+    #[test]
+    fn parses_fragments() {
+        let line = lines("\nRegister A: 7x9\n").next().unwrap();
+        let value = line.value("Register A", ": ").unwrap();
+        assert_eq!(
+            line.parse::<u64, _>(value, |position, text| (position, text)),
+            Err((
+                Position {
+                    line: 2,
+                    column: 13
+                },
+                "7x9".to_string()
+            ))
+        );
+        assert_eq!(line.value("Register B", ": "), None);
+    }
+
+    #[test]
+    fn reads_char_grids() {
+        let cells = char_grid(lines("\n#.\n.#\n"), |c| Some(c == '#')).unwrap();
+        assert_eq!((cells.width, cells.height), (2, 2));
+        assert_eq!(cells.cells, [true, false, false, true]);
+        assert_eq!(
+            char_grid(lines("ab\nc?\n"), |c| c.is_alphabetic().then_some(c)),
+            Err(GridError::UnknownSymbol {
+                position: Position { line: 2, column: 2 },
+                symbol: '?'
+            })
+        );
+    }
+
+    #[test]
+    fn finds_integers() {
+        assert_eq!(
+            integers("p=0,4 v=3,-3").collect::<Vec<_>>(),
+            ["0", "4", "3", "-3"]
+        );
+        assert_eq!(
+            integers("Button A: X+94, Y-34 ends-").collect::<Vec<_>>(),
+            ["94", "-34"]
+        );
+        assert_eq!(integers("none").count(), 0);
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::{BinaryHeap, HashMap, HashSet, hash_map};
+
+use crate::{Cost, Entry, Node};
+
+/// Every optimal path to the cheapest goals, kept as a DAG of predecessors.
+#[derive(Debug, Clone)]
+pub struct AllPaths<N, C> {
+    pub cost: C,
+    nodes: Vec<N>,
+    predecessors: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<N: Node, C> AllPaths<N, C> {
+    /// The goal states reached at the optimal cost.
+    pub fn goals(&self) -> impl Iterator<Item = &N> {
+        self.goals.iter().map(|index| &self.nodes[*index])
+    }
+
+    /// One of the optimal paths, both ends included.
+    pub fn path(&self) -> Vec<N> {
+        let mut path = vec![self.nodes[self.goals[0]].clone()];
+        let mut current = self.goals[0];
+        while let Some(previous) = self.predecessors[current].first() {
+            current = *previous;
+            path.push(self.nodes[current].clone());
+        }
+        path.reverse();
+        path
+    }
+
+    /// Every state that lies on at least one optimal path.
+    pub fn nodes(&self) -> HashSet<&N> {
+        let mut seen = HashSet::new();
+        let mut stack = self.goals.clone();
+        while let Some(index) = stack.pop() {
+            if seen.insert(index) {
+                stack.extend(&self.predecessors[index]);
+            }
+        }
+        seen.into_iter().map(|index| &self.nodes[index]).collect()
+    }
+}
+
+/// Like [`dijkstra`](crate::dijkstra), but keeps searching until every goal at
+/// the optimal cost is found and remembers every optimal predecessor. Step
+/// costs have to be positive.
+pub fn dijkstra_all<N, C, I>(
+    start: &N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<AllPaths<N, C>>
+where
+    N: Node,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = vec![start.clone()];
+    let mut predecessors = vec![vec![]];
+    let mut costs = vec![C::default()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Entry {
+        estimate: C::default(),
+        cost: C::default(),
+        index: 0,
+    }]);
+    let mut best = None;
+    let mut goals = vec![];
+
+    while let Some(Entry { cost, index, .. }) = queue.pop() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if cost > costs[index] {
+            continue;
+        }
+        if success(&nodes[index]) {
+            best = Some(cost);
+            goals.push(index);
+            continue;
+        }
+
+        for (next, step) in successors(&nodes[index]) {
+            let next_cost = cost + step;
+            let next_index = match indices.entry(next) {
+                hash_map::Entry::Vacant(entry) => {
+                    nodes.push(entry.key().clone());
+                    predecessors.push(vec![index]);
+                    costs.push(next_cost);
+                    *entry.insert(nodes.len() - 1)
+                }
+                hash_map::Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost == costs[next_index] {
+                        predecessors[next_index].push(index);
+                    }
+                    if next_cost >= costs[next_index] {
+                        continue;
+                    }
+                    predecessors[next_index] = vec![index];
+                    costs[next_index] = next_cost;
+                    next_index
+                }
+            };
+            queue.push(Entry {
+                estimate: next_cost,
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+
+    Some(AllPaths {
+        cost: best?,
+        nodes,
+        predecessors,
+        goals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_every_optimal_path() {
+        // Two equally cheap routes around a diamond, and a more expensive one.
+        let successors = |node: &char| match node {
+            's' => vec![('a', 1), ('b', 1), ('c', 2)],
+            'a' | 'b' => vec![('g', 1)],
+            'c' => vec![('g', 1)],
+            _ => vec![],
+        };
+        let paths = dijkstra_all(&'s', successors, |n| *n == 'g').unwrap();
+        assert_eq!(paths.cost, 2);
+        assert_eq!(paths.nodes(), HashSet::from([&'s', &'a', &'b', &'g']));
+        assert_eq!(paths.path().len(), 3);
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::{BinaryHeap, HashMap, hash_map};
+
+use crate::{Cost, Entry, Node, reconstruct_path};
+
+/// Cheapest path from `start` to the first state accepted by `success`, guided
+/// by a `heuristic` that must never overestimate the remaining cost. Returns
+/// the total cost and the states along the path, both ends included.
+pub fn astar<N, C, I>(
+    start: &N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Node,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = vec![start.clone()];
+    let mut parents = vec![usize::MAX];
+    let mut costs = vec![C::default()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Entry {
+        estimate: heuristic(start),
+        cost: C::default(),
+        index: 0,
+    }]);
+
+    while let Some(Entry { cost, index, .. }) = queue.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+        if success(&nodes[index]) {
+            return Some((cost, reconstruct_path(&nodes, &parents, index)));
+        }
+
+        for (next, step) in successors(&nodes[index]) {
+            let next_cost = cost + step;
+            let next_index = match indices.entry(next) {
+                hash_map::Entry::Vacant(entry) => {
+                    nodes.push(entry.key().clone());
+                    parents.push(index);
+                    costs.push(next_cost);
+                    *entry.insert(nodes.len() - 1)
+                }
+                hash_map::Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= costs[next_index] {
+                        continue;
+                    }
+                    parents[next_index] = index;
+                    costs[next_index] = next_cost;
+                    next_index
+                }
+            };
+            queue.push(Entry {
+                estimate: next_cost + heuristic(&nodes[next_index]),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+
+    None
+}
+
+/// [`astar`] without a heuristic.
+pub fn dijkstra<N, C, I>(
+    start: &N,
+    successors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Node,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
@@ -0,0 +1,38 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{Node, reconstruct_path};
+
+/// Path with the fewest steps from `start` to the first state accepted by
+/// `success`, both ends included.
+pub fn bfs<N, I>(
+    start: &N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Node,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes = vec![start.clone()];
+    let mut parents = vec![usize::MAX];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if success(&nodes[index]) {
+            return Some(reconstruct_path(&nodes, &parents, index));
+        }
+
+        for next in successors(&nodes[index]) {
+            if indices.contains_key(&next) {
+                continue;
+            }
+            indices.insert(next.clone(), nodes.len());
+            queue.push_back(nodes.len());
+            nodes.push(next);
+            parents.push(index);
+        }
+    }
+
+    None
+}
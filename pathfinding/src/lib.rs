@@ -0,0 +1,106 @@
+mod all_paths;
+mod astar;
+mod bfs;
+
+use std::{cmp::Ordering, hash::Hash, ops::Add};
+
+pub use all_paths::{AllPaths, dijkstra_all};
+pub use astar::{astar, dijkstra};
+pub use bfs::bfs;
+
+/// Path costs: anything that adds up and compares, with `Default` as zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// Search states. Every reached state is stored once and referred to by index.
+pub trait Node: Clone + Eq + Hash {}
+
+impl<N: Clone + Eq + Hash> Node for N {}
+
+/// A queued state, ordered so that `BinaryHeap` pops the lowest estimate first.
+struct Entry<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Entry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Entry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Entry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Entry<C> {}
+
+/// Follows `parent` links back from `index` to the start.
+fn reconstruct_path<N: Clone>(nodes: &[N], parents: &[usize], index: usize) -> Vec<N> {
+    let mut path = vec![nodes[index].clone()];
+    let mut current = index;
+    while parents[current] != usize::MAX {
+        current = parents[current];
+        path.push(nodes[current].clone());
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A weighted graph where the direct edge is not the cheapest route.
+    fn successors(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_finds_cheapest_path() {
+        assert_eq!(
+            dijkstra(&'a', successors, |n| *n == 'd'),
+            Some((6, vec!['a', 'b', 'c', 'd']))
+        );
+        assert_eq!(dijkstra(&'d', successors, |n| *n == 'a'), None);
+    }
+
+    #[test]
+    fn astar_on_a_line() {
+        let goal = 10i32;
+        let result = astar(
+            &0i32,
+            |n| [(n - 1, 1), (n + 1, 1)],
+            |n| (goal - n).abs(),
+            |n| *n == goal,
+        );
+        assert_eq!(
+            result.map(|(cost, path)| (cost, path.len())),
+            Some((10, 11))
+        );
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let path = bfs(&1u32, |n| [n * 2, n + 1], |n| *n == 10).unwrap();
+        assert_eq!(path, vec![1, 2, 4, 5, 10]);
+    }
+}